@@ -4,12 +4,47 @@ use anchor_lang::solana_program::pubkey::Pubkey;
 pub const CONFIG_SEEDS_PREFIX: &[u8] = b"config";
 pub const SWAP_POOL_SEEDS_PREFIX: &[u8] = b"swap_pool";
 pub const TOKEN_VAULT_SEEDS_PREFIX: &[u8] = b"token_vault";
+pub const ROLE_SEEDS_PREFIX: &[u8] = b"role";
+pub const PENDING_CONFIG_UPDATE_SEEDS_PREFIX: &[u8] = b"pending_config_update";
+pub const VESTING_SEEDS_PREFIX: &[u8] = b"vesting";
+pub const VESTING_VAULT_SEEDS_PREFIX: &[u8] = b"vesting_vault";
+pub const CREATOR_VESTING_SCHEDULE_ID: u64 = 0; // Schedule id of the grant made at token creation
+pub const BURN_RECORD_SEEDS_PREFIX: &[u8] = b"burn_record";
+pub const TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX: &[u8] = b"transfer_hook_approval";
+pub const LIMIT_ORDER_SEEDS_PREFIX: &[u8] = b"limit_order";
+pub const LIMIT_ORDER_VAULT_SEEDS_PREFIX: &[u8] = b"limit_order_vault";
+pub const DCA_ORDER_SEEDS_PREFIX: &[u8] = b"dca_order";
+pub const DCA_VAULT_SEEDS_PREFIX: &[u8] = b"dca_vault";
+pub const TRIGGER_ORDER_SEEDS_PREFIX: &[u8] = b"trigger_order";
+pub const TRIGGER_ORDER_VAULT_SEEDS_PREFIX: &[u8] = b"trigger_order_vault";
+pub const LAUNCH_BUY_RECORD_SEEDS_PREFIX: &[u8] = b"launch_buy_record";
+pub const NATIVE_SOL_SEEDS_PREFIX: &[u8] = b"native_sol";
+pub const LIQUIDITY_POSITION_SEEDS_PREFIX: &[u8] = b"liquidity_position";
 
 pub const DECIMALS: u8 = 6;
 
+// Account layout versions. Layout 1 is the deployed baseline and layout 2
+// the current one, migrations only ever go from 1 to 2. Fields added later
+// are carved out of `_padding` and bump the version.
+//
+// Config
+//   1  baseline, no version field (the byte reads 0)
+//   2  pause and guardian, creator pool updates, pending admin, timelock
+//      delay, oracle price age
+//
+// SwapPool
+//   1  baseline, no version field or padding (`SwapPoolV1`)
+//   2  curve and weights, price impact and minimum swap, pending AI Agent
+//      transfer fee, transfer hook restriction, launch protection, liquidity
+//      shares
+pub const LEGACY_ACCOUNT_VERSION: u8 = 1;
+pub const CONFIG_VERSION: u8 = 2;
+pub const SWAP_POOL_VERSION: u8 = 2;
+
 // Swap constants
 pub const DEFAULT_SWAP_FEE_BASIS_POINTS: u16 = 30; // 0.3%
 pub const MAX_SWAP_FEE_BASIS_POINTS: u16 = 1000;  // 10%
+pub const DEFAULT_TRADE_FEE_BASIS_POINTS: u16 = 100; // 1%
 
 // AW3 Token constants 
 pub const AW3_TOKEN_DECIMALS: u8 = 6;
@@ -20,8 +55,8 @@ pub const MINIMUM_SWAP_AMOUNT: u64 = 1000; // Minimum tokens to swap
 
 // Price Oracle constants
 // Feed ID for AW3/USD price feed - you'll need to replace with actual Pyth feed ID
-pub const AW3_FEED_ID: &str = "J83w4HKfqxwcq3BEMMkPFSppX3gqekLyLJBexebFVkix"; // Example AW3 price feed
-pub const MAXIMUM_AGE: u64 = 300; // 5 minutes maximum age for price data
+pub const AW3_FEED_ID: &str = "0xfe650f0367d4a7ef9815a593ea15d36593f0643aaaf0149bb04be67ab851decd"; // Example AW3 price feed, hex as Pyth publishes it
+pub const MAXIMUM_AGE: u64 = 300; // 5 minutes maximum age for price data, oracle managers may lower it
 
 // Default pool ratios (can be adjusted)
 pub const INITIAL_A1_RESERVES: u64 = 1_000_000u64.saturating_mul(10u64.pow(DECIMALS as u32));
@@ -29,6 +64,8 @@ pub const INITIAL_A2_RESERVES: u64 = 1_000_000u64.saturating_mul(10u64.pow(DECIM
 
 // pub const REWARD_FEE: u64 = 5 * LAMPORTS_PER_SOL / 10;
 
+pub const MAX_PUMP_FEE: u64 = 10 * LAMPORTS_PER_SOL;
+
 pub const EXECUTE_FEE: u64 = LAMPORTS_PER_SOL / 10;
 
 pub const PROTOCOL_FEE: u64 = 25 * LAMPORTS_PER_SOL / 10;
@@ -43,9 +80,9 @@ pub const QUOTE_AMOUNT: u64 = 164 * LAMPORTS_PER_SOL;
 pub const COMPLETED_TOKEN_AMOUNT: u64 = TOTAL_SUPPLY * 30 / 100;
 pub const VESTING_AMOUNT: u64 = TOTAL_SUPPLY * 10 / 100;
 
-pub const BURN_AMOUNT: u64 = 5_825_082_690_385u64;
+pub const BURN_AMOUNT: u64 = 5_825_082_690_385u64; // Most the protocol burns per mint, in base units of a 6-decimal token
 
-pub const MINIMUM_SOL_BUY_AMOUNT: u64 = 204 * LAMPORTS_PER_SOL / 100000;
+pub const MINIMUM_SOL_BUY_AMOUNT: u64 = 204 * LAMPORTS_PER_SOL / 100000; // Least native SOL a swap may pay in
 
 pub const SOL_DECIMALS: u8 = 9;
 
@@ -66,12 +103,37 @@ pub const TOKEN_2022_PROGRAM_ID: Pubkey = anchor_spl::token_2022::ID;
 // Decimals
 pub const DEFAULT_TOKEN_DECIMALS: u8 = 9;
 
+// Token metadata limits
+pub const MAX_TOKEN_NAME_LENGTH: usize = 32;
+pub const MAX_TOKEN_SYMBOL_LENGTH: usize = 10;
+pub const MAX_TOKEN_URI_LENGTH: usize = 200;
+
 // Pool configuration
 pub const MIN_POOL_RESERVES: u64 = 1_000_000;      // Minimum reserves to maintain liquidity
 pub const MAX_PRICE_IMPACT: u16 = 500;             // 5% maximum price impact per swap
+pub const MAX_LAUNCH_FEE_BASIS_POINTS: u16 = 5000; // 50% maximum swap fee at launch
+pub const MAX_LAUNCH_PROTECTION_SLOTS: u64 = 9000; // About an hour of launch protection
+
+// Weighted pool configuration (weights in basis points, summing to WEIGHT_DENOMINATOR)
+pub const WEIGHT_DENOMINATOR: u16 = 10000;
+pub const DEFAULT_TOKEN_WEIGHT: u16 = 5000;        // 50/50 pool
+pub const MIN_TOKEN_WEIGHT: u16 = 200;             // 2% minimum weight per side
+pub const MAX_TOKEN_WEIGHT: u16 = 9800;            // 98% maximum weight per side
+pub const MAX_IN_RATIO_BASIS_POINTS: u16 = 5000;   // Swap input capped at 50% of input reserves
 
 // Price precision
 pub const PRICE_PRECISION: u64 = 1_000_000;        // 6 decimal places for price calculations
 
+// Order settings
+pub const LIMIT_ORDER_TIP_BASIS_POINTS: u16 = 10;  // 0.1% of the fill output goes to the filler
+pub const MIN_DCA_INTERVAL: i64 = 60;              // Shortest time between DCA slices
+
 // Administrative constants
+pub const ROLE_FEE_MANAGER: u8 = 1 << 0;     // Fee settings and fee withdrawal
+pub const ROLE_POOL_MANAGER: u8 = 1 << 1;    // Pool status, parameters and closing
+pub const ROLE_PAUSER: u8 = 1 << 2;          // Protocol pause (cannot unpause)
+pub const ROLE_ORACLE_MANAGER: u8 = 1 << 3;  // Price oracle settings
+pub const DEFAULT_CONFIG_UPDATE_DELAY: i64 = 2 * SECONDS_PER_DAY as i64; // 48 hour notice
+pub const MAX_CONFIG_UPDATE_DELAY: i64 = 30 * SECONDS_PER_DAY as i64;
+pub const ALL_ROLES: u8 = ROLE_FEE_MANAGER | ROLE_POOL_MANAGER | ROLE_PAUSER | ROLE_ORACLE_MANAGER;
 pub const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 1000; // 10% maximum transfer fee
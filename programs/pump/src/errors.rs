@@ -40,4 +40,92 @@ pub enum PumpError {
     SlippageExceeded,
     #[msg("Invalid pool state")]
     InvalidPoolState,
+    #[msg("Invalid pool weights")]
+    InvalidPoolWeights,
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    #[msg("Invalid guardian")]
+    InvalidGuardian,
+    #[msg("Price impact too high")]
+    PriceImpactTooHigh,
+    #[msg("No pending ownership transfer")]
+    NoPendingAdmin,
+    #[msg("Invalid role")]
+    InvalidRole,
+    #[msg("Missing required role")]
+    MissingRole,
+    #[msg("Config changes must go through the timelock")]
+    TimelockRequired,
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    #[msg("Invalid fee basis points")]
+    InvalidFeeBasisPoints,
+    #[msg("Invalid pump fee")]
+    InvalidPumpFee,
+    #[msg("Invalid account version")]
+    InvalidAccountVersion,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Invalid token metadata")]
+    InvalidTokenMetadata,
+    #[msg("Invalid initial supply split")]
+    InvalidSupplySplit,
+    #[msg("Transfer hook program is not approved")]
+    TransferHookNotApproved,
+    #[msg("Mint has a freeze authority")]
+    MintHasFreezeAuthority,
+    #[msg("Mint has a permanent delegate")]
+    MintHasPermanentDelegate,
+    #[msg("Mint is non-transferable")]
+    MintNonTransferable,
+    #[msg("Mint uses confidential transfers")]
+    MintConfidentialTransfer,
+    #[msg("Mint freezes new accounts by default")]
+    MintDefaultAccountStateFrozen,
+    #[msg("Pool side is not wrapped SOL")]
+    NativeSolNotSupported,
+    #[msg("Invalid limit order")]
+    InvalidLimitOrder,
+    #[msg("Limit order has expired")]
+    LimitOrderExpired,
+    #[msg("Limit order target price not reached")]
+    LimitOrderPriceNotReached,
+    #[msg("Invalid DCA order")]
+    InvalidDcaOrder,
+    #[msg("DCA slice is not due yet")]
+    DcaSliceNotDue,
+    #[msg("DCA order is fully filled")]
+    DcaOrderCompleted,
+    #[msg("Invalid trigger order")]
+    InvalidTriggerOrder,
+    #[msg("Trigger price not reached")]
+    TriggerNotReached,
+    #[msg("Swap deadline has passed")]
+    SwapDeadlineExceeded,
+    #[msg("Invalid swap recipient")]
+    InvalidRecipient,
+    #[msg("Trading has not started")]
+    TradingNotStarted,
+    #[msg("Invalid launch protection")]
+    InvalidLaunchProtection,
+    #[msg("Launch buy limit exceeded")]
+    LaunchBuyLimitExceeded,
+    #[msg("Launch buy record required")]
+    LaunchBuyRecordRequired,
+    #[msg("Launch protection is active")]
+    LaunchProtectionActive,
+    #[msg("Swap pool must be deactivated first")]
+    SwapPoolActive,
+    #[msg("A config update is already pending")]
+    ConfigUpdatePending,
+    #[msg("Pool does not support liquidity changes")]
+    LiquidityNotSupported,
+    #[msg("Liquidity providers still hold shares in the pool")]
+    LiquidityOutstanding,
+    #[msg("Burn would exceed the committed burn amount")]
+    BurnLimitExceeded,
 }
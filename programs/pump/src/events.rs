@@ -1,12 +1,23 @@
 use anchor_lang::prelude::*;
 
+use crate::{SwapDeadline, UpdateConfigParams};
+
 #[event]
 pub struct TokenCreatedEvent {
-    pub token_mint: Pubkey,
     pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub swap_pool: Pubkey,
     pub name: String,
     pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub transfer_fee_basis_points: u16,
+    pub max_fee: u64,
     pub total_supply: u64,
+    pub creator_amount: u64,
+    pub pool_amount: u64,
+    pub vesting_amount: u64,
+    pub mint_authority_revoked: bool,
     pub timestamp: i64,
 }
 
@@ -20,6 +31,8 @@ pub struct SwapEvent {
     pub input_is_aiw3: bool,
     pub aw3_price: i64,
     pub pool_type: String,
+    pub recipient: Pubkey,            // Owner of the account that received the output
+    pub deadline: Option<SwapDeadline>,
     pub timestamp: i64,
 }
 
@@ -31,6 +44,19 @@ pub struct LiquidityAddedEvent {
     pub aiw3_amount: u64,
     pub ai_agent_amount: u64,
     pub pool_type: String,
+    pub shares: u64,                  // Liquidity shares issued
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemovedEvent {
+    pub user: Pubkey,
+    pub aiw3_token: Pubkey,
+    pub ai_agent_token: Pubkey,
+    pub aiw3_amount: u64,
+    pub ai_agent_amount: u64,
+    pub pool_type: String,
+    pub shares: u64,                  // Liquidity shares redeemed
     pub timestamp: i64,
 }
 
@@ -43,5 +69,333 @@ pub struct PoolCreatedEvent {
     pub initial_aiw3_reserves: u64,
     pub initial_ai_agent_reserves: u64,
     pub swap_fee_basis_points: u16,
+    pub aiw3_weight: u16,
+    pub ai_agent_weight: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolStatusChangedEvent {
+    pub authority: Pubkey,
+    pub swap_pool: Pubkey,
+    pub is_active: bool,
+    pub reason_code: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolPauseChangedEvent {
+    pub authority: Pubkey,
+    pub is_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolClosedEvent {
+    pub authority: Pubkey,
+    pub swap_pool: Pubkey,
+    pub aiw3_token: Pubkey,
+    pub ai_agent_token: Pubkey,
+    pub aiw3_amount: u64,
+    pub ai_agent_amount: u64,
+    pub destination_aiw3_account: Pubkey,
+    pub destination_ai_agent_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolUpdatedEvent {
+    pub authority: Pubkey,
+    pub swap_pool: Pubkey,
+    pub old_swap_fee_basis_points: u16,
+    pub new_swap_fee_basis_points: u16,
+    pub old_max_price_impact_basis_points: u16,
+    pub new_max_price_impact_basis_points: u16,
+    pub old_min_swap_amount: u64,
+    pub new_min_swap_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferProposedEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferAcceptedEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferCancelledEvent {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGrantedEvent {
+    pub admin: Pubkey,
+    pub member: Pubkey,
+    pub granted_roles: u8,
+    pub roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevokedEvent {
+    pub admin: Pubkey,
+    pub member: Pubkey,
+    pub revoked_roles: u8,
+    pub roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdateQueuedEvent {
+    pub proposer: Pubkey,
+    pub params: UpdateConfigParams,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdateExecutedEvent {
+    pub executor: Pubkey,
+    pub proposer: Pubkey,
+    pub params: UpdateConfigParams,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdateCancelledEvent {
+    pub admin: Pubkey,
+    pub proposer: Pubkey,
+    pub params: UpdateConfigParams,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub operator: Pubkey,
+    pub guardian: Pubkey,
+    pub allow_creator_pool_updates: bool,
+    pub trade_fee_basis_points: u16,
+    pub pump_fee: u64,
+    pub config_update_delay: i64,
+    pub max_price_age: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingScheduleCreatedEvent {
+    pub vesting_schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub start_timestamp: i64,
+    pub cliff_timestamp: i64,
+    pub end_timestamp: i64,
+    pub revocable: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingClaimedEvent {
+    pub vesting_schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingRevokedEvent {
+    pub vesting_schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vested_amount: u64,
+    pub unvested_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenBurnedEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub amount: u64,
+    pub total_burned: u64,
+    pub remaining_commitment: u64, // Left to burn before reaching BURN_AMOUNT
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeesHarvestedEvent {
+    pub mint: Pubkey,
+    pub source_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeesWithdrawnEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeeUpdatedEvent {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub old_transfer_fee_basis_points: u16,
+    pub new_transfer_fee_basis_points: u16,
+    pub max_fee: u64,
+    pub activation_epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferHookApprovedEvent {
+    pub admin: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferHookRevokedEvent {
+    pub admin: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitOrderPlacedEvent {
+    pub limit_order: Pubkey,
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub input_is_aiw3: bool,
+    pub amount_in: u64,
+    pub target_price: u64,
+    pub min_amount_out: u64,
+    pub expiry_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitOrderFilledEvent {
+    pub limit_order: Pubkey,
+    pub owner: Pubkey,
+    pub filler: Pubkey,
+    pub swap_pool: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub filler_tip: u64,
+    pub aw3_price: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitOrderCancelledEvent {
+    pub limit_order: Pubkey,
+    pub owner: Pubkey,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DcaOrderOpenedEvent {
+    pub dca_order: Pubkey,
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub total_amount: u64,
+    pub slice_amount: u64,
+    pub min_price: u64,
+    pub interval_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DcaSliceExecutedEvent {
+    pub dca_order: Pubkey,
+    pub owner: Pubkey,
+    pub cranker: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub filled_amount_in: u64,
+    pub filled_amount_out: u64,
+    pub slices_executed: u32,
+    pub next_execution_timestamp: i64,
+    pub aw3_price: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DcaOrderClosedEvent {
+    pub dca_order: Pubkey,
+    pub owner: Pubkey,
+    pub filled_amount_in: u64,
+    pub filled_amount_out: u64,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TriggerOrderPlacedEvent {
+    pub trigger_order: Pubkey,
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub is_stop_loss: bool,
+    pub amount_in: u64,
+    pub trigger_price: i64,
+    pub min_amount_out: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TriggerOrderExecutedEvent {
+    pub trigger_order: Pubkey,
+    pub owner: Pubkey,
+    pub keeper: Pubkey,
+    pub is_stop_loss: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub trigger_price: i64,
+    pub oracle_price: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TriggerOrderCancelledEvent {
+    pub trigger_order: Pubkey,
+    pub owner: Pubkey,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchProtectionSetEvent {
+    pub swap_pool: Pubkey,
+    pub trading_start_timestamp: i64,
+    pub launch_protection_slots: u64,
+    pub max_buy_per_tx: u64,
+    pub max_buy_per_wallet: u64,
+    pub launch_fee_basis_points: u16,
     pub timestamp: i64,
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    #[account(mut)]
+    pub pending_admin: Signer<'info>,
+    #[account(mut,
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = config.pending_admin != Pubkey::default() @ PumpError::NoPendingAdmin,
+        constraint = config.pending_admin == pending_admin.key() @ PumpError::InvalidOwner,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl AcceptOwnership<'_> {
+    pub fn apply(ctx: &mut Context<AcceptOwnership>) -> Result<()> {
+        let previous_admin = ctx.accounts.config.admin;
+
+        ctx.accounts.config.admin = ctx.accounts.config.pending_admin;
+        ctx.accounts.config.pending_admin = Pubkey::default();
+
+        emit!(OwnershipTransferAcceptedEvent {
+            previous_admin,
+            new_admin: ctx.accounts.config.admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct AddLiquidityParams {
+    pub shares: u64,              // Liquidity shares to issue
+    pub max_aiw3_amount: u64,     // Most AIW3 the deposit may take
+    pub max_ai_agent_amount: u64, // Most AI Agent tokens the deposit may take
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token)
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.curve_type == CurveType::WeightedProduct @ PumpError::LiquidityNotSupported,
+        constraint = swap_pool.is_active && !swap_pool.is_locked @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    /// User's share of the pool, created on the first deposit
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + LiquidityPosition::INIT_SPACE,
+        seeds = [LIQUIDITY_POSITION_SEEDS_PREFIX, swap_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub liquidity_position: Box<Account<'info, LiquidityPosition>>,
+
+    /// User's AIW3 token account
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = user,
+        token::token_program = aiw3_token_program
+    )]
+    pub user_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's AI Agent token account
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = user,
+        token::token_program = ai_agent_token_program
+    )]
+    pub user_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AIW3 token vault
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = swap_pool,
+        token::token_program = aiw3_token_program
+    )]
+    pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AI Agent token vault
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = swap_pool,
+        token::token_program = ai_agent_token_program
+    )]
+    pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Approval of the AIW3 mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
+    )]
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Approval of the AI Agent mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddLiquidity<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        params: &AddLiquidityParams,
+    ) -> Result<()> {
+        let swap_pool = &mut ctx.accounts.swap_pool;
+
+        // Hooks can be changed by the mint authority after the pool was created
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        // Deposits are proportional to the reserves, so the price is unchanged
+        let (aiw3_amount, ai_agent_amount) = swap_pool.add_liquidity(params.shares)?;
+        require!(
+            aiw3_amount <= params.max_aiw3_amount && ai_agent_amount <= params.max_ai_agent_amount,
+            PumpError::SlippageExceeded
+        );
+        swap_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
+
+        let swap_pool_key = swap_pool.key();
+        let position = &mut ctx.accounts.liquidity_position;
+        if position.owner == Pubkey::default() {
+            position.swap_pool = swap_pool_key;
+            position.owner = ctx.accounts.user.key();
+            position.bump = ctx.bumps.liquidity_position;
+        }
+        position.shares = position
+            .shares
+            .checked_add(params.shares)
+            .ok_or(PumpError::MathOverflow)?;
+
+        transfer_tokens(
+            &ctx.accounts.aiw3_token_program,
+            ctx.accounts.user_aiw3_account.to_account_info(),
+            &ctx.accounts.aiw3_token_mint,
+            ctx.accounts.pool_aiw3_vault.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            aiw3_amount,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        transfer_tokens(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.user_ai_agent_account.to_account_info(),
+            &ctx.accounts.ai_agent_token_mint,
+            ctx.accounts.pool_ai_agent_vault.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ai_agent_amount,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        emit!(LiquidityAddedEvent {
+            user: ctx.accounts.user.key(),
+            aiw3_token: ctx.accounts.aiw3_token_mint.key(),
+            ai_agent_token: ctx.accounts.ai_agent_token_mint.key(),
+            aiw3_amount,
+            ai_agent_amount,
+            pool_type: ctx.accounts.swap_pool.pool_type.as_str().to_string(),
+            shares: params.shares,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
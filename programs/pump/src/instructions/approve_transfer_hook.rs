@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: ApproveTransferHookParams)]
+pub struct ApproveTransferHook<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + TransferHookApproval::INIT_SPACE,
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, params.program_id.as_ref()],
+        bump,
+    )]
+    pub transfer_hook_approval: Box<Account<'info, TransferHookApproval>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ApproveTransferHook<'_> {
+    pub fn apply(ctx: &mut Context<ApproveTransferHook>, params: ApproveTransferHookParams) -> Result<()> {
+        require!(
+            params.program_id != Pubkey::default(),
+            PumpError::TransferHookNotApproved
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        let approval = &mut ctx.accounts.transfer_hook_approval;
+        approval.program_id = params.program_id;
+        approval.approved_by = ctx.accounts.admin.key();
+        approval.approved_timestamp = timestamp;
+        approval.bump = ctx.bumps.transfer_hook_approval;
+
+        emit!(TransferHookApprovedEvent {
+            admin: ctx.accounts.admin.key(),
+            program_id: params.program_id,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct ApproveTransferHookParams {
+    pub program_id: Pubkey,
+}
@@ -0,0 +1,97 @@
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::*;
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct BurnTreasuryParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct BurnTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Owner of the treasury token accounts
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Token mint being burned
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Protocol treasury for `mint`, the config's associated token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [BURN_RECORD_SEEDS_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    pub burn_record: Box<Account<'info, BurnRecord>>,
+
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl BurnTreasury<'_> {
+    pub fn apply(ctx: &mut Context<BurnTreasury>, params: &BurnTreasuryParams) -> Result<()> {
+        require!(params.amount > 0, PumpError::AmountTooSmall);
+        require!(
+            params.amount <= ctx.accounts.treasury_token_account.amount,
+            PumpError::InsufficientBalance
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mint_key = ctx.accounts.mint.key();
+
+        // BURN_AMOUNT is set for a 6-decimal token, scale it to the mint
+        let burn_limit = crate::utils::scale_to_decimals(BURN_AMOUNT, ctx.accounts.mint.decimals);
+        let burn_record = &mut ctx.accounts.burn_record;
+        burn_record.mint = mint_key;
+        burn_record.bump = ctx.bumps.burn_record;
+        burn_record.record_burn(params.amount, burn_limit, now)?;
+
+        let config_signer_seeds = &[CONFIG_SEEDS_PREFIX, &[ctx.accounts.config.bump]];
+
+        burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_signer_seeds],
+            ),
+            params.amount,
+        )?;
+
+        let total_burned = ctx.accounts.burn_record.total_burned;
+
+        emit!(TokenBurnedEvent {
+            authority: ctx.accounts.admin.key(),
+            mint: mint_key,
+            source: ctx.accounts.treasury_token_account.key(),
+            amount: params.amount,
+            total_burned,
+            remaining_commitment: burn_limit - total_burned,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [PENDING_CONFIG_UPDATE_SEEDS_PREFIX],
+        bump = pending_config_update.bump,
+        has_one = payer,
+    )]
+    pub pending_config_update: Box<Account<'info, PendingConfigUpdate>>,
+    /// Receives the rent of the pending update account
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
+impl CancelConfigUpdate<'_> {
+    pub fn apply(ctx: &mut Context<CancelConfigUpdate>) -> Result<()> {
+        emit!(ConfigUpdateCancelledEvent {
+            admin: ctx.accounts.admin.key(),
+            proposer: ctx.accounts.pending_config_update.proposer,
+            params: ctx.accounts.pending_config_update.params.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [LIMIT_ORDER_SEEDS_PREFIX, limit_order.swap_pool.as_ref(), owner.key().as_ref(), &limit_order.order_id.to_le_bytes()],
+        bump = limit_order.bump,
+        has_one = owner @ PumpError::InvalidOwner,
+        has_one = input_mint @ PumpError::InvalidTokenMint,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    #[account(mint::token_program = input_token_program)]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [LIMIT_ORDER_VAULT_SEEDS_PREFIX, limit_order.key().as_ref()],
+        bump = limit_order.vault_bump,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = input_mint,
+        token::authority = owner,
+        token::token_program = input_token_program
+    )]
+    pub owner_input_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelLimitOrder<'info> {
+    // remaining_accounts: extra accounts required by the input mint's transfer hook
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, CancelLimitOrder<'info>>) -> Result<()> {
+        let limit_order = &ctx.accounts.limit_order;
+        let refunded_amount = ctx.accounts.order_vault.amount;
+
+        let order_id_bytes = limit_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            LIMIT_ORDER_SEEDS_PREFIX,
+            limit_order.swap_pool.as_ref(),
+            limit_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[limit_order.bump],
+        ];
+
+        if refunded_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.input_token_program,
+                ctx.accounts.order_vault.to_account_info(),
+                &ctx.accounts.input_mint,
+                ctx.accounts.owner_input_account.to_account_info(),
+                limit_order.to_account_info(),
+                refunded_amount,
+                &[order_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.input_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.order_vault.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: limit_order.to_account_info(),
+            },
+            &[order_signer_seeds],
+        ))?;
+
+        emit!(LimitOrderCancelledEvent {
+            limit_order: limit_order.key(),
+            owner: limit_order.owner,
+            refunded_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
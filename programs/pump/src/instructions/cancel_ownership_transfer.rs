@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut,
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+        constraint = config.pending_admin != Pubkey::default() @ PumpError::NoPendingAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl CancelOwnershipTransfer<'_> {
+    pub fn apply(ctx: &mut Context<CancelOwnershipTransfer>) -> Result<()> {
+        let cancelled_admin = ctx.accounts.config.pending_admin;
+
+        ctx.accounts.config.pending_admin = Pubkey::default();
+
+        emit!(OwnershipTransferCancelledEvent {
+            admin: ctx.accounts.config.admin,
+            cancelled_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
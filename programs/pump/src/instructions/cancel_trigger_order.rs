@@ -0,0 +1,91 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct CancelTriggerOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TRIGGER_ORDER_SEEDS_PREFIX, trigger_order.swap_pool.as_ref(), owner.key().as_ref(), &trigger_order.order_id.to_le_bytes()],
+        bump = trigger_order.bump,
+        has_one = owner @ PumpError::InvalidOwner,
+    )]
+    pub trigger_order: Box<Account<'info, TriggerOrder>>,
+
+    /// AI Agent Token mint (project token)
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [TRIGGER_ORDER_VAULT_SEEDS_PREFIX, trigger_order.key().as_ref()],
+        bump = trigger_order.vault_bump,
+        token::mint = ai_agent_token_mint,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = owner,
+        token::token_program = ai_agent_token_program
+    )]
+    pub owner_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelTriggerOrder<'info> {
+    // remaining_accounts: extra accounts required by the AI Agent mint's transfer hook
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, CancelTriggerOrder<'info>>) -> Result<()> {
+        let trigger_order = &ctx.accounts.trigger_order;
+        let refunded_amount = ctx.accounts.order_vault.amount;
+
+        let order_id_bytes = trigger_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            TRIGGER_ORDER_SEEDS_PREFIX,
+            trigger_order.swap_pool.as_ref(),
+            trigger_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[trigger_order.bump],
+        ];
+
+        if refunded_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.ai_agent_token_program,
+                ctx.accounts.order_vault.to_account_info(),
+                &ctx.accounts.ai_agent_token_mint,
+                ctx.accounts.owner_ai_agent_account.to_account_info(),
+                trigger_order.to_account_info(),
+                refunded_amount,
+                &[order_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.ai_agent_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.order_vault.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: trigger_order.to_account_info(),
+            },
+            &[order_signer_seeds],
+        ))?;
+
+        emit!(TriggerOrderCancelledEvent {
+            trigger_order: trigger_order.key(),
+            owner: trigger_order.owner,
+            refunded_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
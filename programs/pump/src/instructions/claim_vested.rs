@@ -0,0 +1,88 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEEDS_PREFIX, mint.key().as_ref(), beneficiary.key().as_ref(), &vesting_schedule.schedule_id.to_le_bytes()],
+        bump = vesting_schedule.bump,
+        has_one = beneficiary,
+        has_one = mint,
+    )]
+    pub vesting_schedule: Box<Account<'info, VestingSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_VAULT_SEEDS_PREFIX, vesting_schedule.key().as_ref()],
+        bump = vesting_schedule.vault_bump,
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Beneficiary's token account receiving the released tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = beneficiary,
+        token::token_program = token_program
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimVested<'info> {
+    // remaining_accounts: extra accounts required by the mint's transfer hook
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, ClaimVested<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let amount = ctx.accounts.vesting_schedule.claimable_amount(now)?;
+
+        require!(amount > 0, PumpError::NothingToClaim);
+
+        ctx.accounts.vesting_schedule.claimed_amount = ctx
+            .accounts
+            .vesting_schedule
+            .claimed_amount
+            .checked_add(amount)
+            .ok_or(PumpError::MathOverflow)?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let beneficiary_key = ctx.accounts.beneficiary.key();
+        let schedule_id_bytes = ctx.accounts.vesting_schedule.schedule_id.to_le_bytes();
+        let vesting_signer_seeds = &[
+            VESTING_SEEDS_PREFIX,
+            mint_key.as_ref(),
+            beneficiary_key.as_ref(),
+            schedule_id_bytes.as_ref(),
+            &[ctx.accounts.vesting_schedule.bump],
+        ];
+
+        transfer_tokens(
+            &ctx.accounts.token_program,
+            ctx.accounts.vesting_vault.to_account_info(),
+            &ctx.accounts.mint,
+            ctx.accounts.beneficiary_token_account.to_account_info(),
+            ctx.accounts.vesting_schedule.to_account_info(),
+            amount,
+            &[vesting_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        emit!(VestingClaimedEvent {
+            vesting_schedule: ctx.accounts.vesting_schedule.key(),
+            beneficiary: beneficiary_key,
+            amount,
+            claimed_amount: ctx.accounts.vesting_schedule.claimed_amount,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct CloseDcaOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [DCA_ORDER_SEEDS_PREFIX, dca_order.swap_pool.as_ref(), owner.key().as_ref(), &dca_order.order_id.to_le_bytes()],
+        bump = dca_order.bump,
+        has_one = owner @ PumpError::InvalidOwner,
+    )]
+    pub dca_order: Box<Account<'info, DcaOrder>>,
+
+    /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [DCA_VAULT_SEEDS_PREFIX, dca_order.key().as_ref()],
+        bump = dca_order.vault_bump,
+    )]
+    pub dca_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AIW3 token account receiving what is left
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = owner,
+        token::token_program = aiw3_token_program
+    )]
+    pub owner_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CloseDcaOrder<'info> {
+    // remaining_accounts: extra accounts required by the AIW3 mint's transfer hook
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, CloseDcaOrder<'info>>) -> Result<()> {
+        let dca_order = &ctx.accounts.dca_order;
+        let refunded_amount = ctx.accounts.dca_vault.amount;
+
+        let order_id_bytes = dca_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            DCA_ORDER_SEEDS_PREFIX,
+            dca_order.swap_pool.as_ref(),
+            dca_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[dca_order.bump],
+        ];
+
+        if refunded_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.aiw3_token_program,
+                ctx.accounts.dca_vault.to_account_info(),
+                &ctx.accounts.aiw3_token_mint,
+                ctx.accounts.owner_aiw3_account.to_account_info(),
+                dca_order.to_account_info(),
+                refunded_amount,
+                &[order_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.aiw3_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.dca_vault.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: dca_order.to_account_info(),
+            },
+            &[order_signer_seeds],
+        ))?;
+
+        emit!(DcaOrderClosedEvent {
+            dca_order: dca_order.key(),
+            owner: dca_order.owner,
+            filled_amount_in: dca_order.filled_amount_in,
+            filled_amount_out: dca_order.filled_amount_out,
+            refunded_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
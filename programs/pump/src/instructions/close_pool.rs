@@ -0,0 +1,202 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    /// Admin, pool manager or pool creator
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token), receives fees withheld in the vault
+    #[account(mut, mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token), receives fees withheld in the vault
+    #[account(mut, mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = authority.key() == config.admin
+            || authority.key() == swap_pool.creator
+            || crate::utils::has_role(&role_account, ROLE_POOL_MANAGER) @ PumpError::InvalidOwner,
+        constraint = !swap_pool.is_active && !swap_pool.is_locked @ PumpError::InvalidPoolState,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    /// Pool creator, receives the reclaimed rent
+    #[account(
+        mut,
+        address = swap_pool.creator @ PumpError::InvalidCreator,
+    )]
+    pub creator: SystemAccount<'info>,
+
+    /// Creator's share of a weighted pool, closed with it
+    #[account(
+        mut,
+        close = creator,
+        seeds = [LIQUIDITY_POSITION_SEEDS_PREFIX, swap_pool.key().as_ref(), creator.key().as_ref()],
+        bump = creator_liquidity_position.bump,
+    )]
+    pub creator_liquidity_position: Option<Box<Account<'info, LiquidityPosition>>>,
+
+    /// Pool's AIW3 token vault
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = swap_pool,
+        token::token_program = aiw3_token_program
+    )]
+    pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AI Agent token vault
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = swap_pool,
+        token::token_program = ai_agent_token_program
+    )]
+    pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination for the remaining AIW3 balance
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::token_program = aiw3_token_program
+    )]
+    pub destination_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination for the remaining AI Agent balance
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::token_program = ai_agent_token_program
+    )]
+    pub destination_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClosePool<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, ClosePool<'info>>) -> Result<()> {
+        // The remaining balances are swept to the destination, so only the
+        // creator may still hold shares
+        let creator_shares = ctx
+            .accounts
+            .creator_liquidity_position
+            .as_ref()
+            .map_or(0, |position| position.shares);
+        require!(
+            ctx.accounts.swap_pool.total_shares == creator_shares,
+            PumpError::LiquidityOutstanding
+        );
+
+        let aiw3_amount = ctx.accounts.pool_aiw3_vault.amount;
+        let ai_agent_amount = ctx.accounts.pool_ai_agent_vault.amount;
+
+        let aiw3_token_mint_key = ctx.accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = ctx.accounts.ai_agent_token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
+            &[ctx.accounts.swap_pool.bump],
+        ];
+
+        // Return the remaining balances to the destination
+        if aiw3_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.aiw3_token_program,
+                ctx.accounts.pool_aiw3_vault.to_account_info(),
+                &ctx.accounts.aiw3_token_mint,
+                ctx.accounts.destination_aiw3_account.to_account_info(),
+                ctx.accounts.swap_pool.to_account_info(),
+                aiw3_amount,
+                &[pool_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        if ai_agent_amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.ai_agent_token_program,
+                ctx.accounts.pool_ai_agent_vault.to_account_info(),
+                &ctx.accounts.ai_agent_token_mint,
+                ctx.accounts.destination_ai_agent_account.to_account_info(),
+                ctx.accounts.swap_pool.to_account_info(),
+                ai_agent_amount,
+                &[pool_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        // Token-2022 refuses to close accounts with withheld fees, move them
+        // to the mint where the withdraw authority can still collect them
+        crate::utils::harvest_withheld_fees(
+            &ctx.accounts.aiw3_token_program,
+            ctx.accounts.aiw3_token_mint.to_account_info(),
+            ctx.accounts.pool_aiw3_vault.to_account_info(),
+        )?;
+        crate::utils::harvest_withheld_fees(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.ai_agent_token_mint.to_account_info(),
+            ctx.accounts.pool_ai_agent_vault.to_account_info(),
+        )?;
+
+        // Close both vaults, rent goes back to the pool creator
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.aiw3_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.pool_aiw3_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.swap_pool.to_account_info(),
+            },
+            &[pool_signer_seeds],
+        ))?;
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.ai_agent_token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.pool_ai_agent_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.swap_pool.to_account_info(),
+            },
+            &[pool_signer_seeds],
+        ))?;
+
+        emit!(PoolClosedEvent {
+            authority: ctx.accounts.authority.key(),
+            swap_pool: ctx.accounts.swap_pool.key(),
+            aiw3_token: aiw3_token_mint_key,
+            ai_agent_token: ai_agent_token_mint_key,
+            aiw3_amount,
+            ai_agent_amount,
+            destination_aiw3_account: ctx.accounts.destination_aiw3_account.key(),
+            destination_ai_agent_account: ctx.accounts.destination_ai_agent_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
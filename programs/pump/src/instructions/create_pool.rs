@@ -3,7 +3,7 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{states::swap_pool::PoolType, *};
+use crate::{utils::transfer_tokens, *};
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct CreatePoolParams {
@@ -11,6 +11,56 @@ pub struct CreatePoolParams {
     pub initial_aiw3_amount: u64,
     pub initial_ai_agent_amount: u64,
     pub swap_fee_basis_points: u16,
+    pub curve_type: CurveType,
+    pub aiw3_weight: u16,       // Ignored for oracle pools
+    pub ai_agent_weight: u16,   // Ignored for oracle pools
+    pub restrict_transfer_hooks: bool, // Only allow admin-approved transfer hook programs
+    pub launch_protection: LaunchProtectionParams,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct LaunchProtectionParams {
+    pub trading_start_timestamp: Option<i64>, // Defaults to now
+    pub launch_protection_slots: u64,         // 0 disables the limits and the launch fee
+    pub max_buy_per_tx: u64,                  // 0 for no cap
+    pub max_buy_per_wallet: u64,              // 0 for no cap
+    pub launch_fee_basis_points: u16,
+}
+
+impl LaunchProtectionParams {
+    /// Validates and stores the protections, emits `LaunchProtectionSetEvent`
+    pub fn apply_to(&self, swap_pool: &mut SwapPool, swap_pool_key: Pubkey, now: i64) -> Result<()> {
+        require!(
+            self.launch_protection_slots <= MAX_LAUNCH_PROTECTION_SLOTS,
+            PumpError::InvalidLaunchProtection
+        );
+        require!(
+            self.launch_fee_basis_points <= MAX_LAUNCH_FEE_BASIS_POINTS,
+            PumpError::InvalidLaunchProtection
+        );
+        require!(
+            self.max_buy_per_wallet == 0 || self.max_buy_per_tx <= self.max_buy_per_wallet,
+            PumpError::InvalidLaunchProtection
+        );
+
+        swap_pool.trading_start_timestamp = self.trading_start_timestamp.unwrap_or(now);
+        swap_pool.launch_protection_slots = self.launch_protection_slots;
+        swap_pool.max_buy_per_tx = self.max_buy_per_tx;
+        swap_pool.max_buy_per_wallet = self.max_buy_per_wallet;
+        swap_pool.launch_fee_basis_points = self.launch_fee_basis_points;
+
+        emit!(LaunchProtectionSetEvent {
+            swap_pool: swap_pool_key,
+            trading_start_timestamp: swap_pool.trading_start_timestamp,
+            launch_protection_slots: self.launch_protection_slots,
+            max_buy_per_tx: self.max_buy_per_tx,
+            max_buy_per_wallet: self.max_buy_per_wallet,
+            launch_fee_basis_points: self.launch_fee_basis_points,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -22,13 +72,22 @@ pub struct CreatePool<'info> {
     #[account(
         seeds = [CONFIG_SEEDS_PREFIX],
         bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
     )]
     pub config: Box<Account<'info, Config>>,
 
+    /// Admin co-signature that skips the hazardous mint extension checks
+    #[account(
+        constraint = admin_override.key() == config.admin @ PumpError::NotAdmin,
+    )]
+    pub admin_override: Option<Signer<'info>>,
+
     /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
     pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
     
     /// AI Agent Token mint (project token)  
+    #[account(mint::token_program = ai_agent_token_program)]
     pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -40,12 +99,22 @@ pub struct CreatePool<'info> {
     )]
     pub swap_pool: Box<Account<'info, SwapPool>>,
 
+    /// Creator's share of the pool, required for weighted pools
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + LiquidityPosition::INIT_SPACE,
+        seeds = [LIQUIDITY_POSITION_SEEDS_PREFIX, swap_pool.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_liquidity_position: Option<Box<Account<'info, LiquidityPosition>>>,
+
     /// Creator's AIW3 token account
     #[account(
         mut,
         token::mint = aiw3_token_mint,
         token::authority = creator,
-        token::token_program = token_program_2022
+        token::token_program = aiw3_token_program
     )]
     pub creator_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -54,7 +123,7 @@ pub struct CreatePool<'info> {
         mut,
         token::mint = ai_agent_token_mint,
         token::authority = creator,
-        token::token_program = token_program_2022
+        token::token_program = ai_agent_token_program
     )]
     pub creator_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -64,7 +133,7 @@ pub struct CreatePool<'info> {
         payer = creator,
         token::mint = aiw3_token_mint,
         token::authority = swap_pool,
-        token::token_program = token_program_2022
+        token::token_program = aiw3_token_program
     )]
     pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -74,17 +143,38 @@ pub struct CreatePool<'info> {
         payer = creator,
         token::mint = ai_agent_token_mint,
         token::authority = swap_pool,
-        token::token_program = token_program_2022
+        token::token_program = ai_agent_token_program
     )]
     pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program_2022: Interface<'info, TokenInterface>,
+    /// Approval of the AIW3 mint's transfer hook program, if it has one
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
+    )]
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Approval of the AI Agent mint's transfer hook program, if it has one
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-impl CreatePool<'_> {
-    pub fn apply(ctx: &mut Context<CreatePool>, params: &CreatePoolParams) -> Result<()> {
+impl<'info> CreatePool<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, CreatePool<'info>>,
+        params: &CreatePoolParams,
+    ) -> Result<()> {
         // Validate pool type (enum validation is automatic)
         // Additional validation for fee bounds
         require!(
@@ -98,37 +188,120 @@ impl CreatePool<'_> {
             PumpError::InvalidTokenReserves
         );
 
+        // Weighted pools need both weights in range and summing to the denominator
+        let (aiw3_weight, ai_agent_weight) = match params.curve_type {
+            CurveType::Oracle => (DEFAULT_TOKEN_WEIGHT, DEFAULT_TOKEN_WEIGHT),
+            CurveType::WeightedProduct => {
+                require!(
+                    (MIN_TOKEN_WEIGHT..=MAX_TOKEN_WEIGHT).contains(&params.aiw3_weight) &&
+                    (MIN_TOKEN_WEIGHT..=MAX_TOKEN_WEIGHT).contains(&params.ai_agent_weight),
+                    PumpError::InvalidPoolWeights
+                );
+                require!(
+                    params.aiw3_weight as u32 + params.ai_agent_weight as u32 == WEIGHT_DENOMINATOR as u32,
+                    PumpError::InvalidPoolWeights
+                );
+                (params.aiw3_weight, params.ai_agent_weight)
+            }
+        };
+
+        // Only weighted pools track liquidity shares
+        require!(
+            ctx.accounts.creator_liquidity_position.is_some()
+                == (params.curve_type == CurveType::WeightedProduct),
+            PumpError::InvalidSwapParams
+        );
+
         // Prevent creating pools with the same token
         require!(
             ctx.accounts.aiw3_token_mint.key() != ctx.accounts.ai_agent_token_mint.key(),
             PumpError::InvalidTokenMint
         );
 
-        // Validate token mints are actually mints
+        // Validate token mints are actually mints, the wrapped SOL mint never has supply
         require!(
-            ctx.accounts.aiw3_token_mint.supply > 0 && 
+            (ctx.accounts.aiw3_token_mint.supply > 0 || crate::utils::is_native_mint(&ctx.accounts.aiw3_token_mint.key())) &&
             ctx.accounts.ai_agent_token_mint.supply > 0,
             PumpError::InvalidTokenMint
         );
 
+        // Oracle pools price the AIW3 side from the AW3 feed, which cannot price SOL
+        require!(
+            params.curve_type == CurveType::WeightedProduct
+                || !crate::utils::is_native_mint(&ctx.accounts.aiw3_token_mint.key()),
+            PumpError::NativeSolNotSupported
+        );
+
+        // Freeze authorities, permanent delegates and similar extensions can rug the vaults
+        if ctx.accounts.admin_override.is_none() {
+            crate::utils::check_mint_extensions(&ctx.accounts.aiw3_token_mint.to_account_info())?;
+            crate::utils::check_mint_extensions(&ctx.accounts.ai_agent_token_mint.to_account_info())?;
+        }
+
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            params.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            params.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        // Record the transfer fees the mints charge right now, later changes
+        // to the AI Agent fee go through `set_transfer_fee`
+        let epoch = Clock::get()?.epoch;
+        let aiw3_transfer_fee_basis_points = crate::utils::get_transfer_fee_basis_points(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            epoch,
+        )?;
+        let ai_agent_transfer_fee_basis_points = crate::utils::get_transfer_fee_basis_points(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            epoch,
+        )?;
+
         let swap_pool = &mut ctx.accounts.swap_pool;
         let bump = ctx.bumps.swap_pool;
 
         // Initialize swap pool
+        swap_pool.version = SWAP_POOL_VERSION;
         swap_pool.creator = ctx.accounts.creator.key();
         swap_pool.aiw3_token_mint = ctx.accounts.aiw3_token_mint.key();
         swap_pool.ai_agent_token_mint = ctx.accounts.ai_agent_token_mint.key();
         swap_pool.aiw3_reserves = params.initial_aiw3_amount;
         swap_pool.ai_agent_reserves = params.initial_ai_agent_amount;
         swap_pool.swap_fee_basis_points = params.swap_fee_basis_points;
-        swap_pool.aiw3_transfer_fee_basis_points = 0; // Will be updated when tokens are transferred
-        swap_pool.ai_agent_transfer_fee_basis_points = 0; // Will be updated when tokens are transferred
+        swap_pool.max_price_impact_basis_points = MAX_PRICE_IMPACT;
+        swap_pool.min_swap_amount = MINIMUM_SWAP_AMOUNT;
+        swap_pool.aiw3_transfer_fee_basis_points = aiw3_transfer_fee_basis_points;
+        swap_pool.ai_agent_transfer_fee_basis_points = ai_agent_transfer_fee_basis_points;
         swap_pool.pool_type = params.pool_type.clone();
+        swap_pool.curve_type = params.curve_type.clone();
+        swap_pool.aiw3_weight = aiw3_weight;
+        swap_pool.ai_agent_weight = ai_agent_weight;
+        swap_pool.restrict_transfer_hooks = params.restrict_transfer_hooks;
         swap_pool.is_active = true;
         swap_pool.is_locked = false; // Initialize as unlocked
         swap_pool.bump = bump;
         swap_pool.created_timestamp = Clock::get()?.unix_timestamp;
         swap_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
+        swap_pool.launch_slot = 0; // Set by the first trade
+
+        let swap_pool_key = swap_pool.key();
+        let created_timestamp = swap_pool.created_timestamp;
+        params.launch_protection.apply_to(swap_pool, swap_pool_key, created_timestamp)?;
+
+        // The initial liquidity is issued one share per AIW3 deposited and
+        // stays locked in the creator's position until the pool is closed
+        if let Some(position) = ctx.accounts.creator_liquidity_position.as_mut() {
+            swap_pool.total_shares = params.initial_aiw3_amount;
+            position.swap_pool = swap_pool_key;
+            position.owner = ctx.accounts.creator.key();
+            position.shares = params.initial_aiw3_amount;
+            position.locked_shares = params.initial_aiw3_amount;
+            position.bump = ctx.bumps.creator_liquidity_position;
+        }
 
         // Transfer initial liquidity from creator to pool
         Self::transfer_initial_liquidity(ctx, params)?;
@@ -142,6 +315,8 @@ impl CreatePool<'_> {
             initial_aiw3_reserves: params.initial_aiw3_amount,
             initial_ai_agent_reserves: params.initial_ai_agent_amount,
             swap_fee_basis_points: params.swap_fee_basis_points,
+            aiw3_weight,
+            ai_agent_weight,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
@@ -149,41 +324,93 @@ impl CreatePool<'_> {
     }
 
     fn transfer_initial_liquidity(
-        ctx: &Context<CreatePool>,
+        ctx: &Context<'_, '_, '_, 'info, CreatePool<'info>>,
         params: &CreatePoolParams,
     ) -> Result<()> {
-        use anchor_spl::token_interface::{transfer_checked, TransferChecked};
-
         // Transfer AIW3 tokens from creator to pool vault
-        transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.creator_aiw3_account.to_account_info(),
-                    mint: ctx.accounts.aiw3_token_mint.to_account_info(),
-                    to: ctx.accounts.pool_aiw3_vault.to_account_info(),
-                    authority: ctx.accounts.creator.to_account_info(),
-                },
-            ),
+        transfer_tokens(
+            &ctx.accounts.aiw3_token_program,
+            ctx.accounts.creator_aiw3_account.to_account_info(),
+            &ctx.accounts.aiw3_token_mint,
+            ctx.accounts.pool_aiw3_vault.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
             params.initial_aiw3_amount,
-            ctx.accounts.aiw3_token_mint.decimals,
+            &[],
+            ctx.remaining_accounts,
         )?;
 
         // Transfer AI Agent tokens from creator to pool vault
-        transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.creator_ai_agent_account.to_account_info(),
-                    mint: ctx.accounts.ai_agent_token_mint.to_account_info(),
-                    to: ctx.accounts.pool_ai_agent_vault.to_account_info(),
-                    authority: ctx.accounts.creator.to_account_info(),
-                },
-            ),
+        transfer_tokens(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.creator_ai_agent_account.to_account_info(),
+            &ctx.accounts.ai_agent_token_mint,
+            ctx.accounts.pool_ai_agent_vault.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
             params.initial_ai_agent_amount,
-            ctx.accounts.ai_agent_token_mint.decimals,
+            &[],
+            ctx.remaining_accounts,
         )?;
 
         Ok(())
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn launch_protection() -> LaunchProtectionParams {
+        LaunchProtectionParams {
+            trading_start_timestamp: None,
+            launch_protection_slots: 150,
+            max_buy_per_tx: 1_000,
+            max_buy_per_wallet: 5_000,
+            launch_fee_basis_points: 2_000,
+        }
+    }
+
+    #[test]
+    fn launch_protection_is_stored_on_the_pool() {
+        let mut pool = SwapPool::test_pool();
+
+        launch_protection()
+            .apply_to(&mut pool, Pubkey::default(), 42)
+            .unwrap();
+
+        assert_eq!(pool.trading_start_timestamp, 42);
+        assert_eq!(pool.launch_protection_slots, 150);
+        assert_eq!(pool.max_buy_per_tx, 1_000);
+        assert_eq!(pool.max_buy_per_wallet, 5_000);
+        assert_eq!(pool.launch_fee_basis_points, 2_000);
+    }
+
+    #[test]
+    fn launch_protection_bounds_are_enforced() {
+        let mut pool = SwapPool::test_pool();
+
+        let long_window = LaunchProtectionParams {
+            launch_protection_slots: MAX_LAUNCH_PROTECTION_SLOTS + 1,
+            ..launch_protection()
+        };
+        assert!(long_window.apply_to(&mut pool, Pubkey::default(), 0).is_err());
+
+        let high_fee = LaunchProtectionParams {
+            launch_fee_basis_points: MAX_LAUNCH_FEE_BASIS_POINTS + 1,
+            ..launch_protection()
+        };
+        assert!(high_fee.apply_to(&mut pool, Pubkey::default(), 0).is_err());
+
+        let tx_cap_above_wallet_cap = LaunchProtectionParams {
+            max_buy_per_tx: 6_000,
+            ..launch_protection()
+        };
+        assert!(tx_cap_above_wallet_cap.apply_to(&mut pool, Pubkey::default(), 0).is_err());
+
+        let no_wallet_cap = LaunchProtectionParams {
+            max_buy_per_tx: 6_000,
+            max_buy_per_wallet: 0,
+            ..launch_protection()
+        };
+        assert!(no_wallet_cap.apply_to(&mut pool, Pubkey::default(), 0).is_ok());
+    }
+}
@@ -1,34 +1,62 @@
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, AssociatedToken, Create},
     token_2022::{
-        initialize_mint2, initialize_account3,
+        initialize_mint2, initialize_account3, mint_to, set_authority,
         spl_token_2022::{
             extension::{
+                metadata_pointer::instruction::initialize as initialize_metadata_pointer,
                 transfer_fee::instruction::initialize_transfer_fee_config,
                 ExtensionType,
             },
-            state::Mint as MintState,
+            instruction::AuthorityType,
+            state::{Account as AccountState, Mint as MintState},
         },
-        InitializeMint2, InitializeAccount3,
+        InitializeMint2, InitializeAccount3, MintTo, SetAuthority,
     },
     token_interface::{
-        Mint, TokenAccount, TokenInterface,
+        TokenAccount, TokenInterface,
     },
 };
 
 use anchor_lang::system_program::{create_account, CreateAccount};
+use spl_token_metadata_interface::instruction::initialize as initialize_token_metadata;
 use crate::*;
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct CreateTokenParams {
     pub transfer_fee_basis_points: u16,
     pub max_fee: u64,
-    #[max_len(20)]
     pub name: String,
-    #[max_len(10)]
     pub symbol: String,
+    pub uri: String,
     pub decimals: u8,
     pub initial_supply: u64,
+    pub pool_amount: u64,             // Part of the initial supply seeded into the pool vault
+    pub vesting_amount: u64,          // Part of the initial supply vested to the creator
+    pub revoke_mint_authority: bool,  // Fix the supply once the initial mint is done
+}
+
+impl CreateTokenParams {
+    /// Part of the initial supply minted to the creator. The creator, pool
+    /// and vesting amounts add up to the initial supply, which is capped at
+    /// `TOTAL_SUPPLY` scaled to the token's decimals, and the vested part at
+    /// `VESTING_AMOUNT` scaled the same way
+    pub fn creator_amount(&self) -> std::result::Result<u64, PumpError> {
+        let max_supply = crate::utils::scale_to_decimals(TOTAL_SUPPLY, self.decimals);
+        let max_vesting_amount = crate::utils::scale_to_decimals(VESTING_AMOUNT, self.decimals);
+
+        if self.initial_supply == 0
+            || self.initial_supply > max_supply
+            || self.vesting_amount > max_vesting_amount
+        {
+            return Err(PumpError::InvalidSupplySplit);
+        }
+
+        self.initial_supply
+            .checked_sub(self.pool_amount)
+            .and_then(|amount| amount.checked_sub(self.vesting_amount))
+            .ok_or(PumpError::InvalidSupplySplit)
+    }
 }
 
 #[derive(Accounts)]
@@ -40,6 +68,7 @@ pub struct CreateToken<'info> {
     #[account(
         seeds = [CONFIG_SEEDS_PREFIX],
         bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
     )]
     pub config: Box<Account<'info, Config>>,
 
@@ -70,14 +99,31 @@ pub struct CreateToken<'info> {
     pub pool_token_vault: UncheckedAccount<'info>,
 
     /// Creator's token account to receive initial supply
+    /// CHECK: created in `apply` once the mint exists, address checked against the ATA
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &creator.key(),
+            &token_mint.key(),
+            &token_program_2022.key(),
+        ) @ PumpError::InvalidTokenMint,
+    )]
+    pub creator_token_account: UncheckedAccount<'info>,
+
+    /// Creator's vesting schedule, passed only when `vesting_amount` is non-zero
     #[account(
         init,
         payer = creator,
-        associated_token::mint = token_mint,
-        associated_token::authority = creator,
-        associated_token::token_program = token_program_2022
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [VESTING_SEEDS_PREFIX, token_mint.key().as_ref(), creator.key().as_ref(), &CREATOR_VESTING_SCHEDULE_ID.to_le_bytes()],
+        bump,
     )]
-    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub vesting_schedule: Option<Box<Account<'info, VestingSchedule>>>,
+
+    /// Escrow for the vested part of the supply
+    /// CHECK: created in `apply` once the mint exists, PDA checked there
+    #[account(mut)]
+    pub vesting_vault: Option<UncheckedAccount<'info>>,
 
     /// Fee recipient account
     #[account(mut)]
@@ -94,6 +140,34 @@ impl<'info> CreateToken<'info> {
         ctx: &mut Context<'_, '_, '_, 'info, CreateToken<'info>>,
         params: &CreateTokenParams,
     ) -> Result<()> {
+        require!(
+            !params.name.is_empty() && params.name.len() <= MAX_TOKEN_NAME_LENGTH,
+            PumpError::InvalidTokenMetadata
+        );
+        require!(
+            !params.symbol.is_empty() && params.symbol.len() <= MAX_TOKEN_SYMBOL_LENGTH,
+            PumpError::InvalidTokenMetadata
+        );
+        require!(
+            params.uri.len() <= MAX_TOKEN_URI_LENGTH,
+            PumpError::InvalidTokenMetadata
+        );
+        require!(
+            params.transfer_fee_basis_points <= MAX_TRANSFER_FEE_BASIS_POINTS,
+            PumpError::InvalidFeeBasisPoints
+        );
+
+        // The initial supply is split between the creator, the pool and the vesting escrow
+        let creator_amount = params.creator_amount()?;
+
+        // The vesting accounts are passed exactly when part of the supply is vested
+        let vests = params.vesting_amount > 0;
+        require!(
+            ctx.accounts.vesting_schedule.is_some() == vests
+                && ctx.accounts.vesting_vault.is_some() == vests,
+            PumpError::InvalidVestingSchedule
+        );
+
         let creator_key = ctx.accounts.creator.key();
         let symbol_bytes = params.symbol.as_bytes();
         
@@ -104,13 +178,18 @@ impl<'info> CreateToken<'info> {
         );
         require_keys_eq!(mint_pda, ctx.accounts.token_mint.key(), PumpError::InvalidTokenMint);
 
-        // Calculate mint account size with transfer fee extension
+        // Calculate mint account size with transfer fee and metadata pointer extensions
         let mint_size = ExtensionType::try_calculate_account_len::<MintState>(&[
             ExtensionType::TransferFeeConfig,
+            ExtensionType::MetadataPointer,
         ])?;
 
+        // Token metadata is reallocated into the mint by the token program,
+        // so its rent has to be funded up front
+        let metadata_size = crate::utils::token_metadata_space(&params.name, &params.symbol, &params.uri);
+
         let rent = Rent::get()?;
-        let lamports = rent.minimum_balance(mint_size);
+        let lamports = rent.minimum_balance(mint_size + metadata_size);
 
         // Create mint account
         let mint_signer_seeds = &[
@@ -133,12 +212,14 @@ impl<'info> CreateToken<'info> {
             &ctx.accounts.token_program_2022.key(),
         )?;
 
-        // Initialize transfer fee extension
+        // Initialize transfer fee extension: fee changes go through the pool PDA
+        // via `set_transfer_fee`, withheld fees through the config PDA via
+        // `withdraw_transfer_fees`
         let transfer_fee_config_ix = initialize_transfer_fee_config(
             &ctx.accounts.token_program_2022.key(),
             &ctx.accounts.token_mint.key(),
-            Some(&ctx.accounts.config.fee_recipient),
-            Some(&ctx.accounts.creator.key()),
+            Some(&ctx.accounts.swap_pool.key()),
+            Some(&ctx.accounts.config.key()),
             params.transfer_fee_basis_points,
             params.max_fee,
         )?;
@@ -152,7 +233,20 @@ impl<'info> CreateToken<'info> {
             &[mint_signer_seeds],
         )?;
 
-        // Initialize mint
+        // Point the metadata at the mint itself
+        let metadata_pointer_ix = initialize_metadata_pointer(
+            &ctx.accounts.token_program_2022.key(),
+            &ctx.accounts.token_mint.key(),
+            Some(ctx.accounts.creator.key()),
+            Some(ctx.accounts.token_mint.key()),
+        )?;
+
+        solana_program::program::invoke(
+            &metadata_pointer_ix,
+            &[ctx.accounts.token_mint.to_account_info()],
+        )?;
+
+        // Initialize mint without a freeze authority so it can back a pool
         initialize_mint2(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program_2022.to_account_info(),
@@ -163,25 +257,57 @@ impl<'info> CreateToken<'info> {
             ),
             params.decimals,
             &ctx.accounts.swap_pool.key(),
-            Some(&ctx.accounts.creator.key()),
+            None,
+        )?;
+
+        // Write name, symbol and uri into the mint, signed by the mint authority
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            token_mint_key.as_ref(),
+            &[ctx.bumps.swap_pool],
+        ];
+
+        let token_metadata_ix = initialize_token_metadata(
+            &ctx.accounts.token_program_2022.key(),
+            &ctx.accounts.token_mint.key(),
+            &ctx.accounts.creator.key(),
+            &ctx.accounts.token_mint.key(),
+            &ctx.accounts.swap_pool.key(),
+            params.name.clone(),
+            params.symbol.clone(),
+            params.uri.clone(),
+        );
+
+        solana_program::program::invoke_signed(
+            &token_metadata_ix,
+            &[
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.swap_pool.to_account_info(),
+            ],
+            &[pool_signer_seeds],
         )?;
 
         // Initialize swap pool state
         let swap_pool = &mut ctx.accounts.swap_pool;
+        swap_pool.version = SWAP_POOL_VERSION;
         swap_pool.creator = ctx.accounts.creator.key();
-        swap_pool.token_mint = ctx.accounts.token_mint.key();
-        swap_pool.token_a1_reserves = 0;
-        swap_pool.token_a2_reserves = 0;
-        swap_pool.fee_rate = params.transfer_fee_basis_points;
+        swap_pool.ai_agent_token_mint = ctx.accounts.token_mint.key();
+        swap_pool.aiw3_reserves = 0;
+        swap_pool.ai_agent_reserves = 0;
+        swap_pool.ai_agent_transfer_fee_basis_points = params.transfer_fee_basis_points;
         swap_pool.is_active = true;
         swap_pool.bump = ctx.bumps.swap_pool;
 
-        // Create pool token vault
-        let vault_size = TokenAccount::LEN;
+        // Create pool token vault, sized for the transfer fee extension
+        let vault_size = ExtensionType::try_calculate_account_len::<AccountState>(&[
+            ExtensionType::TransferFeeAmount,
+        ])?;
         let vault_lamports = rent.minimum_balance(vault_size);
 
         let pool_key = ctx.accounts.swap_pool.key();
-        let token_mint_key = ctx.accounts.token_mint.key();
         let vault_signer_seeds = &[
             pool_key.as_ref(),
             token_mint_key.as_ref(),
@@ -215,6 +341,47 @@ impl<'info> CreateToken<'info> {
             ),
         )?;
 
+        // Create the creator's token account now that the mint exists
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.creator.to_account_info(),
+                associated_token: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program_2022.to_account_info(),
+            },
+        ))?;
+
+        if creator_amount > 0 {
+            Self::mint_supply(ctx, ctx.accounts.creator_token_account.to_account_info(), creator_amount)?;
+        }
+
+        if params.pool_amount > 0 {
+            Self::mint_supply(ctx, ctx.accounts.pool_token_vault.to_account_info(), params.pool_amount)?;
+            ctx.accounts.swap_pool.ai_agent_reserves = params.pool_amount;
+        }
+
+        if params.vesting_amount > 0 {
+            Self::create_vesting_escrow(ctx, params.vesting_amount, vault_size, &rent)?;
+        }
+
+        if params.revoke_mint_authority {
+            set_authority(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program_2022.to_account_info(),
+                    SetAuthority {
+                        current_authority: ctx.accounts.swap_pool.to_account_info(),
+                        account_or_mint: ctx.accounts.token_mint.to_account_info(),
+                    },
+                    &[pool_signer_seeds],
+                ),
+                AuthorityType::MintTokens,
+                None,
+            )?;
+        }
+
         // Emit token creation event
         emit!(TokenCreatedEvent {
             creator: ctx.accounts.creator.key(),
@@ -222,12 +389,199 @@ impl<'info> CreateToken<'info> {
             swap_pool: ctx.accounts.swap_pool.key(),
             name: params.name.clone(),
             symbol: params.symbol.clone(),
+            uri: params.uri.clone(),
             decimals: params.decimals,
             transfer_fee_basis_points: params.transfer_fee_basis_points,
             max_fee: params.max_fee,
+            total_supply: params.initial_supply,
+            creator_amount,
+            pool_amount: params.pool_amount,
+            vesting_amount: params.vesting_amount,
+            mint_authority_revoked: params.revoke_mint_authority,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
-} 
\ No newline at end of file
+
+    /// Mint part of the initial supply, signed by the pool as mint authority
+    fn mint_supply(
+        ctx: &Context<'_, '_, '_, 'info, CreateToken<'info>>,
+        to: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            token_mint_key.as_ref(),
+            &[ctx.bumps.swap_pool],
+        ];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_2022.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to,
+                    authority: ctx.accounts.swap_pool.to_account_info(),
+                },
+                &[pool_signer_seeds],
+            ),
+            amount,
+        )
+    }
+
+    /// Create the creator's vesting escrow and lock `amount` in it
+    fn create_vesting_escrow(
+        ctx: &mut Context<'_, '_, '_, 'info, CreateToken<'info>>,
+        amount: u64,
+        vault_size: usize,
+        rent: &Rent,
+    ) -> Result<()> {
+        let vesting_schedule = ctx
+            .accounts
+            .vesting_schedule
+            .as_ref()
+            .ok_or(PumpError::InvalidVestingSchedule)?;
+        let vesting_vault = ctx
+            .accounts
+            .vesting_vault
+            .as_ref()
+            .ok_or(PumpError::InvalidVestingSchedule)?;
+
+        let vesting_schedule_key = vesting_schedule.key();
+        let (vault_key, vault_bump) = Pubkey::find_program_address(
+            &[VESTING_VAULT_SEEDS_PREFIX, vesting_schedule_key.as_ref()],
+            &id(),
+        );
+        require_keys_eq!(vault_key, vesting_vault.key(), PumpError::InvalidVestingSchedule);
+        let vault_signer_seeds = &[
+            VESTING_VAULT_SEEDS_PREFIX,
+            vesting_schedule_key.as_ref(),
+            &[vault_bump],
+        ];
+
+        create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: vesting_vault.to_account_info(),
+                },
+                &[vault_signer_seeds],
+            ),
+            rent.minimum_balance(vault_size),
+            vault_size as u64,
+            &ctx.accounts.token_program_2022.key(),
+        )?;
+
+        initialize_account3(
+            CpiContext::new(
+                ctx.accounts.token_program_2022.to_account_info(),
+                InitializeAccount3 {
+                    account: vesting_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    authority: vesting_schedule.to_account_info(),
+                },
+            ),
+        )?;
+
+        Self::mint_supply(ctx, vesting_vault.to_account_info(), amount)?;
+
+        // Creator allocations are locked, then vest until the full unlock
+        let now = Clock::get()?.unix_timestamp;
+        let cliff_timestamp = now
+            .checked_add(LOCK_PERIOD as i64)
+            .ok_or(PumpError::MathOverflow)?;
+        let end_timestamp = now
+            .checked_add(FULL_UNLOCK_PERIOD as i64)
+            .ok_or(PumpError::MathOverflow)?;
+
+        let creator_key = ctx.accounts.creator.key();
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let vesting_schedule_bump = ctx.bumps.vesting_schedule;
+
+        let vesting_schedule = ctx
+            .accounts
+            .vesting_schedule
+            .as_mut()
+            .ok_or(PumpError::InvalidVestingSchedule)?;
+        vesting_schedule.beneficiary = creator_key;
+        vesting_schedule.schedule_id = CREATOR_VESTING_SCHEDULE_ID;
+        vesting_schedule.mint = token_mint_key;
+        vesting_schedule.funder = creator_key;
+        vesting_schedule.total_amount = amount;
+        vesting_schedule.claimed_amount = 0;
+        vesting_schedule.start_timestamp = now;
+        vesting_schedule.cliff_timestamp = cliff_timestamp;
+        vesting_schedule.end_timestamp = end_timestamp;
+        vesting_schedule.revocable = false;
+        vesting_schedule.revoked = false;
+        vesting_schedule.bump = vesting_schedule_bump;
+        vesting_schedule.vault_bump = vault_bump;
+
+        emit!(VestingScheduleCreatedEvent {
+            vesting_schedule: vesting_schedule_key,
+            beneficiary: creator_key,
+            schedule_id: CREATOR_VESTING_SCHEDULE_ID,
+            mint: token_mint_key,
+            total_amount: amount,
+            start_timestamp: now,
+            cliff_timestamp,
+            end_timestamp,
+            revocable: false,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(initial_supply: u64, pool_amount: u64, vesting_amount: u64) -> CreateTokenParams {
+        CreateTokenParams {
+            transfer_fee_basis_points: 100,
+            max_fee: 1_000_000,
+            name: "AI Agent".to_string(),
+            symbol: "AGENT".to_string(),
+            uri: String::new(),
+            decimals: DECIMALS,
+            initial_supply,
+            pool_amount,
+            vesting_amount,
+            revoke_mint_authority: false,
+        }
+    }
+
+    #[test]
+    fn creator_receives_what_the_pool_and_vesting_leave() {
+        assert_eq!(params(1_000, 0, 0).creator_amount().unwrap(), 1_000);
+        assert_eq!(params(1_000, 600, 100).creator_amount().unwrap(), 300);
+        assert_eq!(params(1_000, 900, 100).creator_amount().unwrap(), 0);
+    }
+
+    #[test]
+    fn supply_split_must_fit_the_initial_supply() {
+        assert!(params(0, 0, 0).creator_amount().is_err());
+        assert!(params(1_000, 1_001, 0).creator_amount().is_err());
+        assert!(params(1_000, 0, 1_001).creator_amount().is_err());
+        assert!(params(1_000, 901, 100).creator_amount().is_err());
+        assert!(params(1_000, u64::MAX, u64::MAX).creator_amount().is_err());
+    }
+
+    #[test]
+    fn supply_caps_scale_with_the_decimals() {
+        assert!(params(TOTAL_SUPPLY, 0, VESTING_AMOUNT).creator_amount().is_ok());
+        assert!(params(TOTAL_SUPPLY + 1, 0, 0).creator_amount().is_err());
+        assert!(params(TOTAL_SUPPLY, 0, VESTING_AMOUNT + 1).creator_amount().is_err());
+
+        let nine_decimals = CreateTokenParams {
+            decimals: 9,
+            ..params(TOTAL_SUPPLY * 1_000, 0, VESTING_AMOUNT * 1_000)
+        };
+        assert!(nine_decimals.creator_amount().is_ok());
+    }
+}
@@ -0,0 +1,148 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct CreateVestingScheduleParams {
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,              // Distinguishes grants to the same beneficiary
+    pub amount: u64,
+    pub start_timestamp: Option<i64>,  // Defaults to now
+    pub cliff_duration: Option<u64>,   // Defaults to LOCK_PERIOD
+    pub vesting_duration: Option<u64>, // Defaults to the rest of FULL_UNLOCK_PERIOD after the cliff
+    pub revocable: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateVestingScheduleParams)]
+pub struct CreateVestingSchedule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [VESTING_SEEDS_PREFIX, mint.key().as_ref(), params.beneficiary.as_ref(), &params.schedule_id.to_le_bytes()],
+        bump,
+    )]
+    pub vesting_schedule: Box<Account<'info, VestingSchedule>>,
+
+    /// Escrow holding the unreleased tokens
+    #[account(
+        init,
+        payer = admin,
+        seeds = [VESTING_VAULT_SEEDS_PREFIX, vesting_schedule.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vesting_schedule,
+        token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Admin's token account funding the schedule
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program
+    )]
+    pub admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateVestingSchedule<'info> {
+    // remaining_accounts: extra accounts required by the mint's transfer hook
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, CreateVestingSchedule<'info>>,
+        params: &CreateVestingScheduleParams,
+    ) -> Result<()> {
+        // The cap is set for a 6-decimal token, scale it to the mint
+        let max_amount = crate::utils::scale_to_decimals(VESTING_AMOUNT, ctx.accounts.mint.decimals);
+        require!(
+            params.amount > 0 && params.amount <= max_amount,
+            PumpError::InvalidVestingSchedule
+        );
+        require!(
+            params.beneficiary != Pubkey::default(),
+            PumpError::InvalidVestingSchedule
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let start_timestamp = params.start_timestamp.unwrap_or(now);
+        let cliff_duration = params.cliff_duration.unwrap_or(LOCK_PERIOD);
+        let vesting_duration = params
+            .vesting_duration
+            .unwrap_or(FULL_UNLOCK_PERIOD.saturating_sub(cliff_duration));
+
+        require!(
+            vesting_duration > 0,
+            PumpError::InvalidVestingSchedule
+        );
+
+        let cliff_timestamp = start_timestamp
+            .checked_add(i64::try_from(cliff_duration).map_err(|_| PumpError::MathOverflow)?)
+            .ok_or(PumpError::MathOverflow)?;
+        let end_timestamp = cliff_timestamp
+            .checked_add(i64::try_from(vesting_duration).map_err(|_| PumpError::MathOverflow)?)
+            .ok_or(PumpError::MathOverflow)?;
+
+        // Escrow the tokens, the schedule tracks what actually arrives
+        transfer_tokens(
+            &ctx.accounts.token_program,
+            ctx.accounts.admin_token_account.to_account_info(),
+            &ctx.accounts.mint,
+            ctx.accounts.vesting_vault.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            params.amount,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        ctx.accounts.vesting_vault.reload()?;
+        let total_amount = ctx.accounts.vesting_vault.amount;
+
+        let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+        vesting_schedule.beneficiary = params.beneficiary;
+        vesting_schedule.schedule_id = params.schedule_id;
+        vesting_schedule.mint = ctx.accounts.mint.key();
+        vesting_schedule.funder = ctx.accounts.admin.key();
+        vesting_schedule.total_amount = total_amount;
+        vesting_schedule.claimed_amount = 0;
+        vesting_schedule.start_timestamp = start_timestamp;
+        vesting_schedule.cliff_timestamp = cliff_timestamp;
+        vesting_schedule.end_timestamp = end_timestamp;
+        vesting_schedule.revocable = params.revocable;
+        vesting_schedule.revoked = false;
+        vesting_schedule.bump = ctx.bumps.vesting_schedule;
+        vesting_schedule.vault_bump = ctx.bumps.vesting_vault;
+
+        emit!(VestingScheduleCreatedEvent {
+            vesting_schedule: vesting_schedule.key(),
+            beneficiary: params.beneficiary,
+            schedule_id: params.schedule_id,
+            mint: vesting_schedule.mint,
+            total_amount,
+            start_timestamp,
+            cliff_timestamp,
+            end_timestamp,
+            revocable: params.revocable,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecuteConfigUpdate<'info> {
+    /// Anyone can execute a queued update once it is due
+    pub executor: Signer<'info>,
+    #[account(mut,
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [PENDING_CONFIG_UPDATE_SEEDS_PREFIX],
+        bump = pending_config_update.bump,
+        has_one = payer,
+    )]
+    pub pending_config_update: Box<Account<'info, PendingConfigUpdate>>,
+    /// Role account of the proposer, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, pending_config_update.proposer.as_ref()],
+        bump = proposer_role_account.bump,
+    )]
+    pub proposer_role_account: Option<Box<Account<'info, RoleAccount>>>,
+    /// Receives the rent of the pending update account
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
+impl ExecuteConfigUpdate<'_> {
+    pub fn apply(ctx: &mut Context<ExecuteConfigUpdate>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= ctx.accounts.pending_config_update.eta,
+            PumpError::TimelockNotElapsed
+        );
+
+        let params = ctx.accounts.pending_config_update.params.clone();
+        let proposer = ctx.accounts.pending_config_update.proposer;
+
+        // Roles revoked while the update was queued take effect here too
+        params.authorize(
+            &ctx.accounts.config,
+            &proposer,
+            &ctx.accounts.proposer_role_account,
+        )?;
+        params.apply_to(&mut ctx.accounts.config, proposer)?;
+
+        emit!(ConfigUpdateExecutedEvent {
+            executor: ctx.accounts.executor.key(),
+            proposer,
+            params,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
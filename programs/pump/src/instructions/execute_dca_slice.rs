@@ -0,0 +1,245 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct ExecuteDcaSlice<'info> {
+    /// Anyone can crank a due slice
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token)
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    #[account(
+        mut,
+        seeds = [DCA_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), dca_order.owner.as_ref(), &dca_order.order_id.to_le_bytes()],
+        bump = dca_order.bump,
+        has_one = swap_pool @ PumpError::InvalidDcaOrder,
+    )]
+    pub dca_order: Box<Account<'info, DcaOrder>>,
+
+    #[account(
+        mut,
+        seeds = [DCA_VAULT_SEEDS_PREFIX, dca_order.key().as_ref()],
+        bump = dca_order.vault_bump,
+    )]
+    pub dca_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AI Agent ATA
+    #[account(
+        mut,
+        associated_token::mint = ai_agent_token_mint,
+        associated_token::authority = dca_order.owner,
+        associated_token::token_program = ai_agent_token_program
+    )]
+    pub owner_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AIW3 token vault
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = swap_pool,
+        token::token_program = aiw3_token_program
+    )]
+    pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AI Agent token vault
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = swap_pool,
+        token::token_program = ai_agent_token_program
+    )]
+    pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pyth price oracle for AW3 token pricing, required for oracle pools
+    pub aw3_price_oracle: Option<Account<'info, PriceUpdateV2>>,
+
+    /// Approval of the AIW3 mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
+    )]
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Approval of the AI Agent mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteDcaSlice<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, ExecuteDcaSlice<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let dca_order = &ctx.accounts.dca_order;
+
+        let amount_in = dca_order.next_slice_amount();
+        require!(
+            amount_in > 0,
+            PumpError::DcaOrderCompleted
+        );
+        require!(
+            now >= dca_order.next_execution_timestamp,
+            PumpError::DcaSliceNotDue
+        );
+        require!(
+            !ctx.accounts.swap_pool.is_locked,
+            PumpError::PoolLocked
+        );
+
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.swap_pool.refresh_transfer_fee(clock.epoch);
+        ctx.accounts.swap_pool.open_launch_window(now, clock.slot)?;
+
+        // Slices are buys, they wait for the launch window to close
+        require!(
+            !ctx.accounts.swap_pool.in_launch_window(clock.slot),
+            PumpError::LaunchProtectionActive
+        );
+
+        let aw3_price = crate::utils::get_pool_aw3_price(
+            &ctx.accounts.swap_pool,
+            ctx.accounts.aw3_price_oracle.as_ref(),
+            ctx.accounts.config.oracle_max_age(),
+        )?;
+        let amount_out = ctx.accounts.swap_pool.calculate_swap_output(
+            amount_in,
+            true,
+            aw3_price,
+            clock.slot,
+        )?;
+
+        require!(
+            amount_out >= dca_order.min_amount_out(amount_in)?,
+            PumpError::SlippageExceeded
+        );
+
+        Self::settle(ctx, amount_in, amount_out)?;
+
+        let swap_pool = &mut ctx.accounts.swap_pool;
+        swap_pool.record_swap(true, amount_in, amount_out)?;
+        swap_pool.last_update_timestamp = now;
+
+        let dca_order = &mut ctx.accounts.dca_order;
+        dca_order.filled_amount_in = dca_order
+            .filled_amount_in
+            .checked_add(amount_in)
+            .ok_or(PumpError::MathOverflow)?;
+        dca_order.filled_amount_out = dca_order
+            .filled_amount_out
+            .checked_add(amount_out)
+            .ok_or(PumpError::MathOverflow)?;
+        dca_order.slices_executed = dca_order.slices_executed.saturating_add(1);
+        // Counted from now so late cranks do not bunch slices together
+        dca_order.next_execution_timestamp = now
+            .checked_add(dca_order.interval_seconds)
+            .ok_or(PumpError::MathOverflow)?;
+
+        emit!(DcaSliceExecutedEvent {
+            dca_order: dca_order.key(),
+            owner: dca_order.owner,
+            cranker: ctx.accounts.cranker.key(),
+            amount_in,
+            amount_out,
+            filled_amount_in: dca_order.filled_amount_in,
+            filled_amount_out: dca_order.filled_amount_out,
+            slices_executed: dca_order.slices_executed,
+            next_execution_timestamp: dca_order.next_execution_timestamp,
+            aw3_price,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Move the slice into the pool and the output to the owner
+    fn settle(
+        ctx: &Context<'_, '_, '_, 'info, ExecuteDcaSlice<'info>>,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let dca_order = &accounts.dca_order;
+
+        let swap_pool_key = accounts.swap_pool.key();
+        let order_id_bytes = dca_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            DCA_ORDER_SEEDS_PREFIX,
+            swap_pool_key.as_ref(),
+            dca_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[dca_order.bump],
+        ];
+
+        transfer_tokens(
+            &accounts.aiw3_token_program,
+            accounts.dca_vault.to_account_info(),
+            &accounts.aiw3_token_mint,
+            accounts.pool_aiw3_vault.to_account_info(),
+            dca_order.to_account_info(),
+            amount_in,
+            &[order_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        let aiw3_token_mint_key = accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = accounts.ai_agent_token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
+            &[accounts.swap_pool.bump],
+        ];
+
+        transfer_tokens(
+            &accounts.ai_agent_token_program,
+            accounts.pool_ai_agent_vault.to_account_info(),
+            &accounts.ai_agent_token_mint,
+            accounts.owner_ai_agent_account.to_account_info(),
+            accounts.swap_pool.to_account_info(),
+            amount_out,
+            &[pool_signer_seeds],
+            ctx.remaining_accounts,
+        )
+    }
+}
@@ -0,0 +1,241 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct ExecuteTriggerOrder<'info> {
+    /// Anyone can execute an order once its trigger is reached
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token)
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TRIGGER_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), trigger_order.owner.as_ref(), &trigger_order.order_id.to_le_bytes()],
+        bump = trigger_order.bump,
+        has_one = swap_pool @ PumpError::InvalidTriggerOrder,
+        has_one = owner @ PumpError::InvalidTriggerOrder,
+    )]
+    pub trigger_order: Box<Account<'info, TriggerOrder>>,
+
+    /// CHECK: order owner, receives the escrow rent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TRIGGER_ORDER_VAULT_SEEDS_PREFIX, trigger_order.key().as_ref()],
+        bump = trigger_order.vault_bump,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AIW3 ATA
+    #[account(
+        mut,
+        associated_token::mint = aiw3_token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = aiw3_token_program
+    )]
+    pub owner_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AIW3 token vault
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = swap_pool,
+        token::token_program = aiw3_token_program
+    )]
+    pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AI Agent token vault
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = swap_pool,
+        token::token_program = ai_agent_token_program
+    )]
+    pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pyth price oracle for AW3 token pricing
+    pub aw3_price_oracle: Account<'info, PriceUpdateV2>,
+
+    /// Approval of the AIW3 mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
+    )]
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Approval of the AI Agent mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteTriggerOrder<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, ExecuteTriggerOrder<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let trigger_order = &ctx.accounts.trigger_order;
+
+        // The trigger only counts with a price fresh enough for this order
+        let oracle_price = crate::utils::get_validated_price(
+            &ctx.accounts.aw3_price_oracle,
+            AW3_FEED_ID,
+            trigger_order.max_price_age,
+        )?;
+        require!(
+            trigger_order.is_triggered(oracle_price),
+            PumpError::TriggerNotReached
+        );
+        require!(
+            !ctx.accounts.swap_pool.is_locked,
+            PumpError::PoolLocked
+        );
+
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.swap_pool.refresh_transfer_fee(clock.epoch);
+        ctx.accounts.swap_pool.open_launch_window(now, clock.slot)?;
+
+        let aw3_price = crate::utils::get_aw3_price(
+            &ctx.accounts.aw3_price_oracle,
+            ctx.accounts.config.oracle_max_age(),
+        )?;
+        let amount_in = trigger_order.amount_in;
+        let amount_out = ctx.accounts.swap_pool.calculate_swap_output(
+            amount_in,
+            false,
+            aw3_price,
+            clock.slot,
+        )?;
+
+        require!(
+            amount_out >= trigger_order.min_amount_out,
+            PumpError::InsufficientAmountOut
+        );
+
+        Self::settle(ctx, amount_in, amount_out)?;
+
+        let swap_pool = &mut ctx.accounts.swap_pool;
+        swap_pool.record_swap(false, amount_in, amount_out)?;
+        swap_pool.last_update_timestamp = now;
+
+        let trigger_order = &ctx.accounts.trigger_order;
+        emit!(TriggerOrderExecutedEvent {
+            trigger_order: trigger_order.key(),
+            owner: trigger_order.owner,
+            keeper: ctx.accounts.keeper.key(),
+            is_stop_loss: trigger_order.kind == TriggerOrderKind::StopLoss,
+            amount_in,
+            amount_out,
+            trigger_price: trigger_order.trigger_price,
+            oracle_price,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Move the escrow into the pool and the proceeds to the owner
+    fn settle(
+        ctx: &Context<'_, '_, '_, 'info, ExecuteTriggerOrder<'info>>,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let trigger_order = &accounts.trigger_order;
+
+        let swap_pool_key = accounts.swap_pool.key();
+        let order_id_bytes = trigger_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            TRIGGER_ORDER_SEEDS_PREFIX,
+            swap_pool_key.as_ref(),
+            trigger_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[trigger_order.bump],
+        ];
+
+        transfer_tokens(
+            &accounts.ai_agent_token_program,
+            accounts.order_vault.to_account_info(),
+            &accounts.ai_agent_token_mint,
+            accounts.pool_ai_agent_vault.to_account_info(),
+            trigger_order.to_account_info(),
+            amount_in,
+            &[order_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        let aiw3_token_mint_key = accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = accounts.ai_agent_token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
+            &[accounts.swap_pool.bump],
+        ];
+
+        transfer_tokens(
+            &accounts.aiw3_token_program,
+            accounts.pool_aiw3_vault.to_account_info(),
+            &accounts.aiw3_token_mint,
+            accounts.owner_aiw3_account.to_account_info(),
+            accounts.swap_pool.to_account_info(),
+            amount_out,
+            &[pool_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        // The empty escrow's rent goes back to the owner
+        close_account(CpiContext::new_with_signer(
+            accounts.ai_agent_token_program.to_account_info(),
+            CloseAccount {
+                account: accounts.order_vault.to_account_info(),
+                destination: accounts.owner.to_account_info(),
+                authority: trigger_order.to_account_info(),
+            },
+            &[order_signer_seeds],
+        ))
+    }
+}
@@ -0,0 +1,293 @@
+use anchor_spl::token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct FillLimitOrder<'info> {
+    /// Anyone can fill an order once its target is reached
+    #[account(mut)]
+    pub filler: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token)
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [LIMIT_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), limit_order.owner.as_ref(), &limit_order.order_id.to_le_bytes()],
+        bump = limit_order.bump,
+        has_one = swap_pool @ PumpError::InvalidLimitOrder,
+        has_one = owner @ PumpError::InvalidLimitOrder,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// CHECK: order owner, receives the escrow rent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LIMIT_ORDER_VAULT_SEEDS_PREFIX, limit_order.key().as_ref()],
+        bump = limit_order.vault_bump,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AIW3 token vault
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = swap_pool,
+        token::token_program = aiw3_token_program
+    )]
+    pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool's AI Agent token vault
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = swap_pool,
+        token::token_program = ai_agent_token_program
+    )]
+    pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's account for the output mint
+    #[account(
+        mut,
+        constraint = owner_output_account.mint == limit_order.output_mint @ PumpError::InvalidTokenMint,
+        constraint = owner_output_account.owner == limit_order.owner @ PumpError::InvalidOwner,
+    )]
+    pub owner_output_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Filler's account for the output mint, receives the tip
+    #[account(
+        mut,
+        constraint = filler_output_account.mint == limit_order.output_mint @ PumpError::InvalidTokenMint,
+    )]
+    pub filler_output_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pyth price oracle for AW3 token pricing, required for oracle pools
+    pub aw3_price_oracle: Option<Account<'info, PriceUpdateV2>>,
+
+    /// Approval of the AIW3 mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
+    )]
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Approval of the AI Agent mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FillLimitOrder<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, FillLimitOrder<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let limit_order = &ctx.accounts.limit_order;
+
+        require!(
+            now <= limit_order.expiry_timestamp,
+            PumpError::LimitOrderExpired
+        );
+        require!(
+            !ctx.accounts.swap_pool.is_locked,
+            PumpError::PoolLocked
+        );
+
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            ctx.accounts.swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        let clock = Clock::get()?;
+        let amount_in = limit_order.amount_in;
+        let input_is_aiw3 = limit_order.input_is_aiw3;
+
+        ctx.accounts.swap_pool.refresh_transfer_fee(clock.epoch);
+        ctx.accounts.swap_pool.open_launch_window(now, clock.slot)?;
+
+        // Buys placed ahead of launch would skip the per-wallet limits
+        require!(
+            !(input_is_aiw3 && ctx.accounts.swap_pool.in_launch_window(clock.slot)),
+            PumpError::LaunchProtectionActive
+        );
+
+        // Fill at the pool's current price, the owner's share must meet the target
+        let aw3_price = crate::utils::get_pool_aw3_price(
+            &ctx.accounts.swap_pool,
+            ctx.accounts.aw3_price_oracle.as_ref(),
+            ctx.accounts.config.oracle_max_age(),
+        )?;
+        let amount_out = ctx.accounts.swap_pool.calculate_swap_output(
+            amount_in,
+            input_is_aiw3,
+            aw3_price,
+            clock.slot,
+        )?;
+
+        let filler_tip = LimitOrder::tip(amount_out);
+        let owner_amount = amount_out
+            .checked_sub(filler_tip)
+            .ok_or(PumpError::MathOverflow)?;
+
+        require!(
+            owner_amount >= limit_order.min_amount_out,
+            PumpError::LimitOrderPriceNotReached
+        );
+
+        Self::settle(ctx, amount_in, owner_amount, filler_tip)?;
+
+        let swap_pool = &mut ctx.accounts.swap_pool;
+        swap_pool.record_swap(input_is_aiw3, amount_in, amount_out)?;
+        swap_pool.last_update_timestamp = now;
+
+        emit!(LimitOrderFilledEvent {
+            limit_order: ctx.accounts.limit_order.key(),
+            owner: ctx.accounts.limit_order.owner,
+            filler: ctx.accounts.filler.key(),
+            swap_pool: swap_pool.key(),
+            amount_in,
+            amount_out: owner_amount,
+            filler_tip,
+            aw3_price,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Move the escrow into the pool and pay the owner and the filler
+    fn settle(
+        ctx: &Context<'_, '_, '_, 'info, FillLimitOrder<'info>>,
+        amount_in: u64,
+        owner_amount: u64,
+        filler_tip: u64,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let limit_order = &accounts.limit_order;
+
+        let (input_program, input_mint, input_vault, output_program, output_mint, output_vault) =
+            if limit_order.input_is_aiw3 {
+                (
+                    &accounts.aiw3_token_program,
+                    &accounts.aiw3_token_mint,
+                    &accounts.pool_aiw3_vault,
+                    &accounts.ai_agent_token_program,
+                    &accounts.ai_agent_token_mint,
+                    &accounts.pool_ai_agent_vault,
+                )
+            } else {
+                (
+                    &accounts.ai_agent_token_program,
+                    &accounts.ai_agent_token_mint,
+                    &accounts.pool_ai_agent_vault,
+                    &accounts.aiw3_token_program,
+                    &accounts.aiw3_token_mint,
+                    &accounts.pool_aiw3_vault,
+                )
+            };
+
+        let swap_pool_key = accounts.swap_pool.key();
+        let order_id_bytes = limit_order.order_id.to_le_bytes();
+        let order_signer_seeds = &[
+            LIMIT_ORDER_SEEDS_PREFIX,
+            swap_pool_key.as_ref(),
+            limit_order.owner.as_ref(),
+            order_id_bytes.as_ref(),
+            &[limit_order.bump],
+        ];
+
+        let aiw3_token_mint_key = accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = accounts.ai_agent_token_mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
+            &[accounts.swap_pool.bump],
+        ];
+
+        // Escrow to the pool
+        transfer_tokens(
+            input_program,
+            accounts.order_vault.to_account_info(),
+            input_mint,
+            input_vault.to_account_info(),
+            limit_order.to_account_info(),
+            amount_in,
+            &[order_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        // Pool to the owner and the filler
+        transfer_tokens(
+            output_program,
+            output_vault.to_account_info(),
+            output_mint,
+            accounts.owner_output_account.to_account_info(),
+            accounts.swap_pool.to_account_info(),
+            owner_amount,
+            &[pool_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+
+        if filler_tip > 0 {
+            transfer_tokens(
+                output_program,
+                output_vault.to_account_info(),
+                output_mint,
+                accounts.filler_output_account.to_account_info(),
+                accounts.swap_pool.to_account_info(),
+                filler_tip,
+                &[pool_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        // The empty escrow's rent goes back to the owner
+        close_account(CpiContext::new_with_signer(
+            input_program.to_account_info(),
+            CloseAccount {
+                account: accounts.order_vault.to_account_info(),
+                destination: accounts.owner.to_account_info(),
+                authority: limit_order.to_account_info(),
+            },
+            &[order_signer_seeds],
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: GrantRoleParams)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + RoleAccount::INIT_SPACE,
+        seeds = [ROLE_SEEDS_PREFIX, params.member.as_ref()],
+        bump,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl GrantRole<'_> {
+    pub fn apply(ctx: &mut Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+        require!(
+            params.roles != 0 && params.roles & !ALL_ROLES == 0,
+            PumpError::InvalidRole
+        );
+
+        let role_account = &mut ctx.accounts.role_account;
+        role_account.member = params.member;
+        role_account.roles |= params.roles;
+        role_account.bump = ctx.bumps.role_account;
+
+        emit!(RoleGrantedEvent {
+            admin: ctx.accounts.admin.key(),
+            member: params.member,
+            granted_roles: params.roles,
+            roles: role_account.roles,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct GrantRoleParams {
+    pub member: Pubkey,
+    pub roles: u8,
+}
@@ -0,0 +1,47 @@
+use anchor_spl::{
+    token_2022::{spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint, Token2022},
+    token_interface::Mint,
+};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    /// Token mint whose withheld fees are collected
+    #[account(mut, mint::token_program = token_program_2022)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program_2022: Program<'info, Token2022>,
+    // remaining_accounts: token accounts of `mint` holding withheld fees
+}
+
+impl HarvestTransferFees<'_> {
+    /// Permissionless sweep of withheld fees from token accounts into the mint
+    pub fn apply<'info>(ctx: &mut Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>) -> Result<()> {
+        require!(
+            !ctx.remaining_accounts.is_empty(),
+            PumpError::NothingToClaim
+        );
+
+        let sources: Vec<&Pubkey> = ctx.remaining_accounts.iter().map(|account| account.key).collect();
+
+        let harvest_ix = harvest_withheld_tokens_to_mint(
+            &ctx.accounts.token_program_2022.key(),
+            &ctx.accounts.mint.key(),
+            &sources,
+        )?;
+
+        let mut account_infos = vec![ctx.accounts.mint.to_account_info()];
+        account_infos.extend(ctx.remaining_accounts.iter().cloned());
+
+        solana_program::program::invoke(&harvest_ix, &account_infos)?;
+
+        emit!(TransferFeesHarvestedEvent {
+            mint: ctx.accounts.mint.key(),
+            source_count: sources.len() as u16,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
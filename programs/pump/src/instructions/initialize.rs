@@ -44,11 +44,33 @@ impl Initialize<'_> {
         config.operator = ctx.accounts.operator.key();
         config.fee_recipient = ctx.accounts.fee_recipient.key();
         config.bump = ctx.bumps.config;
+        config.version = CONFIG_VERSION;
 
         config.mop_mint = ctx.accounts.mop_mint.key();
 
-        config.trade_fee_basis_points = params.trade_fee_basis_points.unwrap_or(100);
+        config.trade_fee_basis_points = params
+            .trade_fee_basis_points
+            .unwrap_or(DEFAULT_TRADE_FEE_BASIS_POINTS);
         config.pump_fee = params.pump_fee.unwrap_or(0);
+        config.config_update_delay = params
+            .config_update_delay
+            .unwrap_or(DEFAULT_CONFIG_UPDATE_DELAY);
+        config.max_price_age = MAXIMUM_AGE;
+
+        config.validate()?;
+
+        emit!(ConfigUpdatedEvent {
+            authority: config.admin,
+            fee_recipient: config.fee_recipient,
+            operator: config.operator,
+            guardian: config.guardian,
+            allow_creator_pool_updates: config.allow_creator_pool_updates,
+            trade_fee_basis_points: config.trade_fee_basis_points,
+            pump_fee: config.pump_fee,
+            config_update_delay: config.config_update_delay,
+            max_price_age: config.max_price_age,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
@@ -58,4 +80,5 @@ impl Initialize<'_> {
 pub struct InitializeParams {
     pub trade_fee_basis_points: Option<u16>,
     pub pump_fee: Option<u64>,
+    pub config_update_delay: Option<i64>,
 }
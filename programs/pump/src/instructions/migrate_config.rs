@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+/// Config fields are only ever carved out of the padding, so migrating never
+/// changes the account size
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut,
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+        constraint = config.layout_version() < CONFIG_VERSION @ PumpError::InvalidAccountVersion,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl MigrateConfig<'_> {
+    pub fn apply(ctx: &mut Context<MigrateConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let old_version = config.layout_version();
+
+        // Fields carved out of the padding read as zero on baseline configs,
+        // only the timelock delay and the price age need non-zero defaults
+        config.config_update_delay = DEFAULT_CONFIG_UPDATE_DELAY;
+        config.max_price_age = MAXIMUM_AGE;
+
+        config.version = CONFIG_VERSION;
+
+        emit!(AccountMigratedEvent {
+            account: config.key(),
+            old_version,
+            new_version: CONFIG_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
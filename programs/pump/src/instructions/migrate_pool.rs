@@ -0,0 +1,88 @@
+use anchor_lang::{
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: legacy pools cannot be deserialized with the current layout,
+    /// owner, discriminator and PDA are verified in `apply`
+    #[account(mut)]
+    pub swap_pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MigratePool<'_> {
+    pub fn apply(ctx: &mut Context<MigratePool>) -> Result<()> {
+        let swap_pool_info = ctx.accounts.swap_pool.to_account_info();
+
+        require_keys_eq!(*swap_pool_info.owner, crate::ID, PumpError::InvalidPoolState);
+
+        // Only baseline pools need migrating, they predate the version field
+        // and are smaller than the current layout
+        let swap_pool = {
+            let data = swap_pool_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == SwapPool::DISCRIMINATOR,
+                PumpError::InvalidPoolState
+            );
+            require!(data.len() == SwapPool::V1_SIZE, PumpError::InvalidAccountVersion);
+
+            SwapPool::from_v1(SwapPoolV1::deserialize(&mut &data[8..])?)
+        };
+
+        let expected_key = Pubkey::create_program_address(
+            &[
+                SWAP_POOL_SEEDS_PREFIX,
+                swap_pool.aiw3_token_mint.as_ref(),
+                swap_pool.ai_agent_token_mint.as_ref(),
+                &[swap_pool.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(PumpError::InvalidPoolState))?;
+        require_keys_eq!(expected_key, swap_pool_info.key(), PumpError::InvalidPoolState);
+
+        // Top up rent for the larger account before growing it
+        let minimum_balance = Rent::get()?.minimum_balance(SwapPool::SIZE);
+        let shortfall = minimum_balance.saturating_sub(swap_pool_info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: swap_pool_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        swap_pool_info.realloc(SwapPool::SIZE, true)?;
+
+        swap_pool.try_serialize(&mut &mut swap_pool_info.try_borrow_mut_data()?[..])?;
+
+        emit!(AccountMigratedEvent {
+            account: swap_pool_info.key(),
+            old_version: LEGACY_ACCOUNT_VERSION,
+            new_version: SWAP_POOL_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
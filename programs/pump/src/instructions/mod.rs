@@ -1,15 +1,83 @@
+pub use accept_ownership::*;
+pub use add_liquidity::*;
+pub use approve_transfer_hook::*;
+pub use burn_treasury::*;
+pub use cancel_config_update::*;
+pub use cancel_limit_order::*;
+pub use cancel_ownership_transfer::*;
+pub use cancel_trigger_order::*;
+pub use claim_vested::*;
+pub use close_dca_order::*;
+pub use close_pool::*;
 pub use create_pool::*;
 pub use create_token::*;
+pub use create_vesting_schedule::*;
+pub use execute_config_update::*;
+pub use execute_dca_slice::*;
+pub use execute_trigger_order::*;
+pub use fill_limit_order::*;
+pub use grant_role::*;
+pub use harvest_transfer_fees::*;
 pub use initialize::*;
+pub use migrate_config::*;
+pub use migrate_pool::*;
+pub use open_dca_order::*;
+pub use place_limit_order::*;
+pub use place_trigger_order::*;
+pub use queue_config_update::*;
+pub use quote::*;
+pub use remove_liquidity::*;
+pub use revoke_role::*;
+pub use revoke_transfer_hook::*;
+pub use revoke_vesting::*;
+pub use set_paused::*;
+pub use set_pool_status::*;
+pub use set_transfer_fee::*;
 pub use swap::*;
 pub use transfer_ownership::*;
 pub use update_config::*;
+pub use update_pool::*;
+pub use withdraw_transfer_fees::*;
 // pub use vanity_pump::*;
 
+mod accept_ownership;
+mod add_liquidity;
+mod approve_transfer_hook;
+mod burn_treasury;
+mod cancel_config_update;
+mod cancel_limit_order;
+mod cancel_ownership_transfer;
+mod cancel_trigger_order;
+mod claim_vested;
+mod close_dca_order;
+mod close_pool;
 mod create_pool;
 mod create_token;
+mod create_vesting_schedule;
+mod execute_config_update;
+mod execute_dca_slice;
+mod execute_trigger_order;
+mod fill_limit_order;
+mod grant_role;
+mod harvest_transfer_fees;
 mod initialize;
+mod migrate_config;
+mod migrate_pool;
+mod open_dca_order;
+mod place_limit_order;
+mod place_trigger_order;
+mod queue_config_update;
+mod quote;
+mod remove_liquidity;
+mod revoke_role;
+mod revoke_transfer_hook;
+mod revoke_vesting;
+mod set_paused;
+mod set_pool_status;
+mod set_transfer_fee;
 mod swap;
 mod transfer_ownership;
 mod update_config;
+mod update_pool;
+mod withdraw_transfer_fees;
 // mod vanity_pump;
@@ -0,0 +1,156 @@
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct OpenDcaOrderParams {
+    pub order_id: u64,
+    pub total_amount: u64,
+    pub slice_amount: u64,
+    pub interval_seconds: i64,
+    pub min_price: u64,        // Minimum AI Agent out per AIW3 in, scaled by PRICE_PRECISION
+}
+
+#[derive(Accounts)]
+#[instruction(params: OpenDcaOrderParams)]
+pub struct OpenDcaOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token), the side being sold
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token), the side being bought
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DcaOrder::INIT_SPACE,
+        seeds = [DCA_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), owner.key().as_ref(), &params.order_id.to_le_bytes()],
+        bump,
+    )]
+    pub dca_order: Box<Account<'info, DcaOrder>>,
+
+    /// Escrow holding the AIW3 still to be sold
+    #[account(
+        init,
+        payer = owner,
+        seeds = [DCA_VAULT_SEEDS_PREFIX, dca_order.key().as_ref()],
+        bump,
+        token::mint = aiw3_token_mint,
+        token::authority = dca_order,
+        token::token_program = aiw3_token_program
+    )]
+    pub dca_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AIW3 token account funding the order
+    #[account(
+        mut,
+        token::mint = aiw3_token_mint,
+        token::authority = owner,
+        token::token_program = aiw3_token_program
+    )]
+    pub owner_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AI Agent ATA, receives every slice
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = ai_agent_token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = ai_agent_token_program
+    )]
+    pub owner_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenDcaOrder<'info> {
+    // remaining_accounts: extra accounts required by the AIW3 mint's transfer hook
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, OpenDcaOrder<'info>>,
+        params: &OpenDcaOrderParams,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            params.slice_amount >= ctx.accounts.swap_pool.min_swap_amount,
+            PumpError::AmountTooSmall
+        );
+        require!(
+            params.total_amount >= params.slice_amount
+                && params.interval_seconds >= MIN_DCA_INTERVAL
+                && params.min_price > 0,
+            PumpError::InvalidDcaOrder
+        );
+
+        transfer_tokens(
+            &ctx.accounts.aiw3_token_program,
+            ctx.accounts.owner_aiw3_account.to_account_info(),
+            &ctx.accounts.aiw3_token_mint,
+            ctx.accounts.dca_vault.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            params.total_amount,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        // The order sells what actually arrived in escrow
+        ctx.accounts.dca_vault.reload()?;
+        let total_amount = ctx.accounts.dca_vault.amount;
+
+        let dca_order = &mut ctx.accounts.dca_order;
+        dca_order.owner = ctx.accounts.owner.key();
+        dca_order.swap_pool = ctx.accounts.swap_pool.key();
+        dca_order.order_id = params.order_id;
+        dca_order.total_amount = total_amount;
+        dca_order.slice_amount = params.slice_amount;
+        dca_order.min_price = params.min_price;
+        dca_order.interval_seconds = params.interval_seconds;
+        dca_order.next_execution_timestamp = now; // First slice is due right away
+        dca_order.filled_amount_in = 0;
+        dca_order.filled_amount_out = 0;
+        dca_order.slices_executed = 0;
+        dca_order.created_timestamp = now;
+        dca_order.bump = ctx.bumps.dca_order;
+        dca_order.vault_bump = ctx.bumps.dca_vault;
+
+        emit!(DcaOrderOpenedEvent {
+            dca_order: dca_order.key(),
+            owner: dca_order.owner,
+            swap_pool: dca_order.swap_pool,
+            total_amount,
+            slice_amount: params.slice_amount,
+            min_price: params.min_price,
+            interval_seconds: params.interval_seconds,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
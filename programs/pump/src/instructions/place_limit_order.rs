@@ -0,0 +1,150 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct PlaceLimitOrderParams {
+    pub order_id: u64,
+    pub amount_in: u64,
+    pub target_price: u64,     // Minimum output per input, scaled by PRICE_PRECISION
+    pub expiry_timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: PlaceLimitOrderParams)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SWAP_POOL_SEEDS_PREFIX, swap_pool.aiw3_token_mint.as_ref(), swap_pool.ai_agent_token_mint.as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    /// Mint of the side being sold, either side of the pool
+    #[account(
+        mint::token_program = input_token_program,
+        constraint = input_mint.key() == swap_pool.aiw3_token_mint
+            || input_mint.key() == swap_pool.ai_agent_token_mint @ PumpError::InvalidTokenMint,
+    )]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + LimitOrder::INIT_SPACE,
+        seeds = [LIMIT_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), owner.key().as_ref(), &params.order_id.to_le_bytes()],
+        bump,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// Escrow holding the input until the order is filled or cancelled
+    #[account(
+        init,
+        payer = owner,
+        seeds = [LIMIT_ORDER_VAULT_SEEDS_PREFIX, limit_order.key().as_ref()],
+        bump,
+        token::mint = input_mint,
+        token::authority = limit_order,
+        token::token_program = input_token_program
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's token account funding the order
+    #[account(
+        mut,
+        token::mint = input_mint,
+        token::authority = owner,
+        token::token_program = input_token_program
+    )]
+    pub owner_input_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceLimitOrder<'info> {
+    // remaining_accounts: extra accounts required by the input mint's transfer hook
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, PlaceLimitOrder<'info>>,
+        params: &PlaceLimitOrderParams,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            params.amount_in >= ctx.accounts.swap_pool.min_swap_amount,
+            PumpError::AmountTooSmall
+        );
+        require!(
+            params.target_price > 0 && params.expiry_timestamp > now,
+            PumpError::InvalidLimitOrder
+        );
+
+        transfer_tokens(
+            &ctx.accounts.input_token_program,
+            ctx.accounts.owner_input_account.to_account_info(),
+            &ctx.accounts.input_mint,
+            ctx.accounts.order_vault.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            params.amount_in,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        // The order sells what actually arrived in escrow
+        ctx.accounts.order_vault.reload()?;
+        let amount_in = ctx.accounts.order_vault.amount;
+        let min_amount_out = LimitOrder::target_amount_out(amount_in, params.target_price)?;
+
+        require!(
+            min_amount_out > 0,
+            PumpError::InvalidLimitOrder
+        );
+
+        let swap_pool = &ctx.accounts.swap_pool;
+        let input_is_aiw3 = ctx.accounts.input_mint.key() == swap_pool.aiw3_token_mint;
+        let output_mint = if input_is_aiw3 {
+            swap_pool.ai_agent_token_mint
+        } else {
+            swap_pool.aiw3_token_mint
+        };
+
+        let limit_order = &mut ctx.accounts.limit_order;
+        limit_order.owner = ctx.accounts.owner.key();
+        limit_order.swap_pool = swap_pool.key();
+        limit_order.input_mint = ctx.accounts.input_mint.key();
+        limit_order.output_mint = output_mint;
+        limit_order.input_is_aiw3 = input_is_aiw3;
+        limit_order.order_id = params.order_id;
+        limit_order.amount_in = amount_in;
+        limit_order.target_price = params.target_price;
+        limit_order.min_amount_out = min_amount_out;
+        limit_order.expiry_timestamp = params.expiry_timestamp;
+        limit_order.created_timestamp = now;
+        limit_order.bump = ctx.bumps.limit_order;
+        limit_order.vault_bump = ctx.bumps.order_vault;
+
+        emit!(LimitOrderPlacedEvent {
+            limit_order: limit_order.key(),
+            owner: limit_order.owner,
+            swap_pool: limit_order.swap_pool,
+            input_is_aiw3,
+            amount_in,
+            target_price: params.target_price,
+            min_amount_out,
+            expiry_timestamp: params.expiry_timestamp,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct PlaceTriggerOrderParams {
+    pub order_id: u64,
+    pub kind: TriggerOrderKind,
+    pub amount_in: u64,
+    pub trigger_price: i64,
+    pub min_amount_out: u64,
+    pub max_price_age: Option<u64>, // Defaults to the config's price age
+}
+
+#[derive(Accounts)]
+#[instruction(params: PlaceTriggerOrderParams)]
+pub struct PlaceTriggerOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// AIW3 Token mint (platform token), received on execution
+    #[account(mint::token_program = aiw3_token_program)]
+    pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// AI Agent Token mint (project token), the side being protected
+    #[account(mint::token_program = ai_agent_token_program)]
+    pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Triggers compare against the AW3 oracle price, which only oracle pools are priced by
+    #[account(
+        seeds = [SWAP_POOL_SEEDS_PREFIX, aiw3_token_mint.key().as_ref(), ai_agent_token_mint.key().as_ref()],
+        bump = swap_pool.bump,
+        constraint = swap_pool.is_active @ PumpError::SwapPoolNotActive,
+        constraint = swap_pool.curve_type == CurveType::Oracle @ PumpError::InvalidTriggerOrder,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + TriggerOrder::INIT_SPACE,
+        seeds = [TRIGGER_ORDER_SEEDS_PREFIX, swap_pool.key().as_ref(), owner.key().as_ref(), &params.order_id.to_le_bytes()],
+        bump,
+    )]
+    pub trigger_order: Box<Account<'info, TriggerOrder>>,
+
+    /// Escrow holding the AI Agent tokens until execution or cancellation
+    #[account(
+        init,
+        payer = owner,
+        seeds = [TRIGGER_ORDER_VAULT_SEEDS_PREFIX, trigger_order.key().as_ref()],
+        bump,
+        token::mint = ai_agent_token_mint,
+        token::authority = trigger_order,
+        token::token_program = ai_agent_token_program
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AI Agent token account funding the order
+    #[account(
+        mut,
+        token::mint = ai_agent_token_mint,
+        token::authority = owner,
+        token::token_program = ai_agent_token_program
+    )]
+    pub owner_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's AIW3 ATA, receives the proceeds
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = aiw3_token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = aiw3_token_program
+    )]
+    pub owner_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceTriggerOrder<'info> {
+    // remaining_accounts: extra accounts required by the AI Agent mint's transfer hook
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, PlaceTriggerOrder<'info>>,
+        params: &PlaceTriggerOrderParams,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let oracle_max_age = ctx.accounts.config.oracle_max_age();
+        let max_price_age = params.max_price_age.unwrap_or(oracle_max_age);
+
+        require!(
+            params.amount_in >= ctx.accounts.swap_pool.min_swap_amount,
+            PumpError::AmountTooSmall
+        );
+        require!(
+            params.trigger_price > 0
+                && params.min_amount_out > 0
+                && max_price_age > 0
+                && max_price_age <= oracle_max_age,
+            PumpError::InvalidTriggerOrder
+        );
+
+        transfer_tokens(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.owner_ai_agent_account.to_account_info(),
+            &ctx.accounts.ai_agent_token_mint,
+            ctx.accounts.order_vault.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            params.amount_in,
+            &[],
+            ctx.remaining_accounts,
+        )?;
+
+        // The order sells what actually arrived in escrow
+        ctx.accounts.order_vault.reload()?;
+        let amount_in = ctx.accounts.order_vault.amount;
+
+        let trigger_order = &mut ctx.accounts.trigger_order;
+        trigger_order.owner = ctx.accounts.owner.key();
+        trigger_order.swap_pool = ctx.accounts.swap_pool.key();
+        trigger_order.order_id = params.order_id;
+        trigger_order.kind = params.kind;
+        trigger_order.amount_in = amount_in;
+        trigger_order.trigger_price = params.trigger_price;
+        trigger_order.min_amount_out = params.min_amount_out;
+        trigger_order.max_price_age = max_price_age;
+        trigger_order.created_timestamp = now;
+        trigger_order.bump = ctx.bumps.trigger_order;
+        trigger_order.vault_bump = ctx.bumps.order_vault;
+
+        emit!(TriggerOrderPlacedEvent {
+            trigger_order: trigger_order.key(),
+            owner: trigger_order.owner,
+            swap_pool: trigger_order.swap_pool,
+            is_stop_loss: params.kind == TriggerOrderKind::StopLoss,
+            amount_in,
+            trigger_price: params.trigger_price,
+            min_amount_out: params.min_amount_out,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
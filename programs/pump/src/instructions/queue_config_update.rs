@@ -0,0 +1,118 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct QueueConfigUpdate<'info> {
+    /// Admin, or a fee or oracle manager for their settings
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// Only one update can be queued at a time, only the admin may replace it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PendingConfigUpdate::INIT_SPACE,
+        seeds = [PENDING_CONFIG_UPDATE_SEEDS_PREFIX],
+        bump,
+    )]
+    pub pending_config_update: Box<Account<'info, PendingConfigUpdate>>,
+    /// Rent payer of the update being replaced, required when one is pending
+    #[account(mut)]
+    pub previous_payer: Option<SystemAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl QueueConfigUpdate<'_> {
+    pub fn apply(ctx: &mut Context<QueueConfigUpdate>, params: UpdateConfigParams) -> Result<()> {
+        params.authorize(
+            &ctx.accounts.config,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.role_account,
+        )?;
+
+        // Reject updates that could never be executed
+        let mut updated_config = Config::clone(&ctx.accounts.config);
+        params.patch(&mut updated_config);
+        updated_config.validate()?;
+
+        if ctx.accounts.pending_config_update.proposer != Pubkey::default() {
+            Self::replace_pending_update(ctx)?;
+        }
+
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(ctx.accounts.config.config_update_delay)
+            .ok_or(PumpError::MathOverflow)?;
+
+        let pending_config_update = &mut ctx.accounts.pending_config_update;
+        pending_config_update.proposer = ctx.accounts.authority.key();
+        pending_config_update.payer = ctx.accounts.authority.key();
+        pending_config_update.params = params.clone();
+        pending_config_update.eta = eta;
+        pending_config_update.bump = ctx.bumps.pending_config_update;
+
+        emit!(ConfigUpdateQueuedEvent {
+            proposer: ctx.accounts.authority.key(),
+            params,
+            eta,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel the pending update in favor of the admin's, moving its rent
+    /// from the previous payer to the admin
+    fn replace_pending_update(ctx: &mut Context<QueueConfigUpdate>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.admin,
+            PumpError::ConfigUpdatePending
+        );
+
+        let previous_payer = ctx
+            .accounts
+            .previous_payer
+            .as_ref()
+            .ok_or(PumpError::ConfigUpdatePending)?;
+        require_keys_eq!(
+            previous_payer.key(),
+            ctx.accounts.pending_config_update.payer,
+            PumpError::ConfigUpdatePending
+        );
+
+        if previous_payer.key() != ctx.accounts.authority.key() {
+            let rent = ctx.accounts.pending_config_update.to_account_info().lamports();
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: previous_payer.to_account_info(),
+                    },
+                ),
+                rent,
+            )?;
+        }
+
+        emit!(ConfigUpdateCancelledEvent {
+            admin: ctx.accounts.authority.key(),
+            proposer: ctx.accounts.pending_config_update.proposer,
+            params: ctx.accounts.pending_config_update.params.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::*;
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteParams {
+    pub amount_in: u64,
+    pub input_is_aiw3: bool,
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SWAP_POOL_SEEDS_PREFIX, swap_pool.aiw3_token_mint.as_ref(), swap_pool.ai_agent_token_mint.as_ref()],
+        bump = swap_pool.bump,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+
+    /// Pyth price oracle for AW3 token pricing, required for oracle pools
+    pub aw3_price_oracle: Option<Account<'info, PriceUpdateV2>>,
+}
+
+impl Quote<'_> {
+    /// Returns the amount a swap would currently pay out, before transfer fees
+    pub fn apply(ctx: &mut Context<Quote>, params: &QuoteParams) -> Result<u64> {
+        require!(
+            params.amount_in >= ctx.accounts.swap_pool.min_swap_amount,
+            PumpError::AmountTooSmall
+        );
+
+        let aw3_price = crate::utils::get_pool_aw3_price(
+            &ctx.accounts.swap_pool,
+            ctx.accounts.aw3_price_oracle.as_ref(),
+            ctx.accounts.config.oracle_max_age(),
+        )?;
+
+        let amount_out = ctx.accounts.swap_pool.calculate_swap_output(
+            params.amount_in,
+            params.input_is_aiw3,
+            aw3_price,
+            Clock::get()?.slot,
+        )?;
+
+        Ok(amount_out)
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        seeds = [ROLE_SEEDS_PREFIX, role_account.member.as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+}
+
+impl RevokeRole<'_> {
+    pub fn apply(ctx: &mut Context<RevokeRole>, params: RevokeRoleParams) -> Result<()> {
+        require!(
+            params.roles != 0 && params.roles & !ALL_ROLES == 0,
+            PumpError::InvalidRole
+        );
+
+        let role_account = &mut ctx.accounts.role_account;
+        role_account.roles &= !params.roles;
+
+        emit!(RoleRevokedEvent {
+            admin: ctx.accounts.admin.key(),
+            member: role_account.member,
+            revoked_roles: params.roles,
+            roles: role_account.roles,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Members without any role left do not need an account
+        if role_account.roles == 0 {
+            role_account.close(ctx.accounts.admin.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct RevokeRoleParams {
+    pub roles: u8,
+}
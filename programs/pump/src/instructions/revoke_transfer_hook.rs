@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RevokeTransferHook<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, transfer_hook_approval.program_id.as_ref()],
+        bump = transfer_hook_approval.bump,
+    )]
+    pub transfer_hook_approval: Box<Account<'info, TransferHookApproval>>,
+}
+
+impl RevokeTransferHook<'_> {
+    pub fn apply(ctx: &mut Context<RevokeTransferHook>) -> Result<()> {
+        // Pools restricted to approved hooks stop trading until the mint's hook changes
+        emit!(TransferHookRevokedEvent {
+            admin: ctx.accounts.admin.key(),
+            program_id: ctx.accounts.transfer_hook_approval.program_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{utils::transfer_tokens, *};
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        has_one = admin @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEEDS_PREFIX, mint.key().as_ref(), vesting_schedule.beneficiary.as_ref(), &vesting_schedule.schedule_id.to_le_bytes()],
+        bump = vesting_schedule.bump,
+        has_one = mint,
+        constraint = vesting_schedule.revocable && !vesting_schedule.revoked @ PumpError::InvalidVestingSchedule,
+    )]
+    pub vesting_schedule: Box<Account<'info, VestingSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_VAULT_SEEDS_PREFIX, vesting_schedule.key().as_ref()],
+        bump = vesting_schedule.vault_bump,
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination for the unvested tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RevokeVesting<'info> {
+    // remaining_accounts: extra accounts required by the mint's transfer hook
+    pub fn apply(ctx: &mut Context<'_, '_, '_, 'info, RevokeVesting<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+
+        // The beneficiary keeps whatever has vested so far
+        let vested_amount = vesting_schedule.vested_amount(now)?;
+        let unvested_amount = vesting_schedule
+            .total_amount
+            .checked_sub(vested_amount)
+            .ok_or(PumpError::MathOverflow)?;
+
+        vesting_schedule.total_amount = vested_amount;
+        vesting_schedule.end_timestamp = now.min(vesting_schedule.end_timestamp);
+        vesting_schedule.cliff_timestamp = vesting_schedule.cliff_timestamp.min(vesting_schedule.end_timestamp);
+        vesting_schedule.revoked = true;
+
+        if unvested_amount > 0 {
+            let mint_key = ctx.accounts.mint.key();
+            let beneficiary_key = ctx.accounts.vesting_schedule.beneficiary;
+            let schedule_id_bytes = ctx.accounts.vesting_schedule.schedule_id.to_le_bytes();
+            let vesting_signer_seeds = &[
+                VESTING_SEEDS_PREFIX,
+                mint_key.as_ref(),
+                beneficiary_key.as_ref(),
+                schedule_id_bytes.as_ref(),
+                &[ctx.accounts.vesting_schedule.bump],
+            ];
+
+            transfer_tokens(
+                &ctx.accounts.token_program,
+                ctx.accounts.vesting_vault.to_account_info(),
+                &ctx.accounts.mint,
+                ctx.accounts.destination_token_account.to_account_info(),
+                ctx.accounts.vesting_schedule.to_account_info(),
+                unvested_amount,
+                &[vesting_signer_seeds],
+                ctx.remaining_accounts,
+            )?;
+        }
+
+        emit!(VestingRevokedEvent {
+            vesting_schedule: ctx.accounts.vesting_schedule.key(),
+            beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+            vested_amount,
+            unvested_amount,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
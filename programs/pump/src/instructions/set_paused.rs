@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Admin, or the guardian or a pauser when pausing
+    pub authority: Signer<'info>,
+
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+
+    #[account(mut,
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl SetPaused<'_> {
+    pub fn apply(ctx: &mut Context<SetPaused>, params: SetPausedParams) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let config = &mut ctx.accounts.config;
+
+        // The guardian and pausers can only pause, unpausing is reserved for the admin
+        if authority != config.admin {
+            require!(
+                params.is_paused
+                    && (authority == config.guardian
+                        || crate::utils::has_role(&ctx.accounts.role_account, ROLE_PAUSER)),
+                PumpError::InvalidGuardian
+            );
+        }
+
+        config.is_paused = params.is_paused;
+
+        emit!(ProtocolPauseChangedEvent {
+            authority,
+            is_paused: params.is_paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetPausedParams {
+    pub is_paused: bool,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetPoolStatus<'info> {
+    /// Admin, operator or pool manager
+    pub authority: Signer<'info>,
+
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = authority.key() == config.admin
+            || authority.key() == config.operator
+            || crate::utils::has_role(&role_account, ROLE_POOL_MANAGER) @ PumpError::InvalidOperator,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, swap_pool.aiw3_token_mint.as_ref(), swap_pool.ai_agent_token_mint.as_ref()],
+        bump = swap_pool.bump,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+}
+
+impl SetPoolStatus<'_> {
+    pub fn apply(ctx: &mut Context<SetPoolStatus>, params: SetPoolStatusParams) -> Result<()> {
+        let swap_pool = &mut ctx.accounts.swap_pool;
+
+        require!(
+            swap_pool.is_active != params.is_active,
+            PumpError::InvalidPoolState
+        );
+
+        swap_pool.is_active = params.is_active;
+        swap_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(PoolStatusChangedEvent {
+            authority: ctx.accounts.authority.key(),
+            swap_pool: swap_pool.key(),
+            is_active: params.is_active,
+            reason_code: params.reason_code,
+            timestamp: swap_pool.last_update_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetPoolStatusParams {
+    pub is_active: bool,
+    pub reason_code: u8, // Off-chain incident code, recorded in the event only
+}
@@ -0,0 +1,109 @@
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::transfer_fee::instruction::set_transfer_fee,
+    token_interface::{Mint, TokenInterface},
+};
+
+use crate::*;
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetTransferFeeParams {
+    pub transfer_fee_basis_points: u16,
+    pub max_fee: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    /// Admin or token creator
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Token mint created by `create_token`
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool created with the token, holds the transfer fee config authority
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, mint.key().as_ref()],
+        bump = token_pool.bump,
+        constraint = authority.key() == config.admin || authority.key() == token_pool.creator @ PumpError::InvalidOwner,
+    )]
+    pub token_pool: Box<Account<'info, SwapPool>>,
+
+    /// Trading pool for the token, kept in sync when provided
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, swap_pool.aiw3_token_mint.as_ref(), mint.key().as_ref()],
+        bump = swap_pool.bump,
+    )]
+    pub swap_pool: Option<Box<Account<'info, SwapPool>>>,
+
+    pub token_program_2022: Interface<'info, TokenInterface>,
+}
+
+impl SetTransferFee<'_> {
+    pub fn apply(ctx: &mut Context<SetTransferFee>, params: &SetTransferFeeParams) -> Result<()> {
+        require!(
+            params.transfer_fee_basis_points <= MAX_TRANSFER_FEE_BASIS_POINTS,
+            PumpError::InvalidFeeBasisPoints
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let pool_signer_seeds = &[
+            SWAP_POOL_SEEDS_PREFIX,
+            mint_key.as_ref(),
+            &[ctx.accounts.token_pool.bump],
+        ];
+
+        let set_transfer_fee_ix = set_transfer_fee(
+            &ctx.accounts.token_program_2022.key(),
+            &mint_key,
+            &ctx.accounts.token_pool.key(),
+            &[],
+            params.transfer_fee_basis_points,
+            params.max_fee,
+        )?;
+
+        solana_program::program::invoke_signed(
+            &set_transfer_fee_ix,
+            &[
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.token_pool.to_account_info(),
+            ],
+            &[pool_signer_seeds],
+        )?;
+
+        // Token-2022 only applies the new fee two epochs from now
+        let clock = Clock::get()?;
+        let activation_epoch = clock.epoch.checked_add(2).ok_or(PumpError::MathOverflow)?;
+
+        let token_pool = &mut ctx.accounts.token_pool;
+        token_pool.refresh_transfer_fee(clock.epoch);
+        let old_transfer_fee_basis_points = token_pool.ai_agent_transfer_fee_basis_points;
+        token_pool.pending_ai_agent_transfer_fee_basis_points = params.transfer_fee_basis_points;
+        token_pool.transfer_fee_activation_epoch = activation_epoch;
+
+        if let Some(swap_pool) = ctx.accounts.swap_pool.as_mut() {
+            swap_pool.refresh_transfer_fee(clock.epoch);
+            swap_pool.pending_ai_agent_transfer_fee_basis_points = params.transfer_fee_basis_points;
+            swap_pool.transfer_fee_activation_epoch = activation_epoch;
+        }
+
+        emit!(TransferFeeUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            mint: mint_key,
+            old_transfer_fee_basis_points,
+            new_transfer_fee_basis_points: params.transfer_fee_basis_points,
+            max_fee: params.max_fee,
+            activation_epoch,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,22 +1,34 @@
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::{
-        spl_token_2022::extension::transfer_fee::TransferFee,
-        transfer_checked_with_fee, TransferCheckedWithFee,
-    },
-    token_interface::{
-        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
-    },
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
 };
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
-use crate::*;
+use crate::{utils::transfer_tokens, *};
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct SwapParams {
     pub amount_in: u64,
     pub minimum_amount_out: u64,
     pub input_is_aiw3: bool,  // true if swapping AIW3 -> AI Agent, false if AI Agent -> AIW3
+    pub native_sol: bool,     // Pay in / receive native lamports on a wrapped SOL AIW3 side
+    pub deadline: Option<SwapDeadline>, // Reject the swap if it lands after this point
+    pub recipient: Option<Pubkey>,      // Owner of the output account, defaults to the user
+}
+
+#[derive(Clone, Copy, AnchorDeserialize, AnchorSerialize)]
+pub enum SwapDeadline {
+    Timestamp(i64), // Last unix timestamp the swap may execute at
+    Slot(u64),      // Last slot the swap may execute in
+}
+
+impl SwapDeadline {
+    pub fn has_passed(&self, clock: &Clock) -> bool {
+        match self {
+            SwapDeadline::Timestamp(timestamp) => clock.unix_timestamp > *timestamp,
+            SwapDeadline::Slot(slot) => clock.slot > *slot,
+        }
+    }
 }
 
 #[derive(Accounts)]
@@ -28,13 +40,16 @@ pub struct Swap<'info> {
     #[account(
         seeds = [CONFIG_SEEDS_PREFIX],
         bump = config.bump,
+        constraint = !config.is_paused @ PumpError::ProtocolPaused,
     )]
     pub config: Box<Account<'info, Config>>,
 
     /// AIW3 Token mint (platform token)
+    #[account(mint::token_program = aiw3_token_program)]
     pub aiw3_token_mint: Box<InterfaceAccount<'info, Mint>>,
     
     /// AI Agent Token mint (project token)  
+    #[account(mint::token_program = ai_agent_token_program)]
     pub ai_agent_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -48,10 +63,11 @@ pub struct Swap<'info> {
 
     /// User's AIW3 token account
     #[account(
-        mut,
-        token::mint = aiw3_token_mint,
-        token::authority = user,
-        token::token_program = token_program_2022
+        init_if_needed,
+        payer = user,
+        associated_token::mint = aiw3_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = aiw3_token_program
     )]
     pub user_aiw3_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -61,16 +77,33 @@ pub struct Swap<'info> {
         payer = user,
         associated_token::mint = ai_agent_token_mint,
         associated_token::authority = user,
-        associated_token::token_program = token_program_2022
+        associated_token::token_program = ai_agent_token_program
     )]
     pub user_ai_agent_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Output token account owned by `params.recipient`, required when it is set
+    #[account(mut)]
+    pub recipient_output_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Temporary wrapped SOL account for native SOL swaps, holding the wrapped
+    /// input or the output and closed to the user within the swap
+    #[account(
+        init,
+        payer = user,
+        seeds = [NATIVE_SOL_SEEDS_PREFIX, user.key().as_ref()],
+        bump,
+        token::mint = aiw3_token_mint,
+        token::authority = user,
+        token::token_program = aiw3_token_program
+    )]
+    pub native_sol_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// Pool's AIW3 token vault
     #[account(
         mut,
         token::mint = aiw3_token_mint,
         token::authority = swap_pool,
-        token::token_program = token_program_2022
+        token::token_program = aiw3_token_program
     )]
     pub pool_aiw3_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -79,27 +112,96 @@ pub struct Swap<'info> {
         mut,
         token::mint = ai_agent_token_mint,
         token::authority = swap_pool,
-        token::token_program = token_program_2022
+        token::token_program = ai_agent_token_program
     )]
     pub pool_ai_agent_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Fee recipient account (for swap fees)
+    /// Pyth price oracle for AW3 token pricing, required for oracle pools
+    pub aw3_price_oracle: Option<Account<'info, PriceUpdateV2>>,
+
+    /// Approval of the AIW3 mint's transfer hook program, for pools restricting hooks
     #[account(
-        mut,
-        constraint = fee_recipient.owner == config.fee_recipient @ PumpError::InvalidFeeRecipient
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, aiw3_hook_approval.program_id.as_ref()],
+        bump = aiw3_hook_approval.bump,
     )]
-    pub fee_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub aiw3_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
 
-    /// Pyth price oracle for AW3 token pricing
-    pub aw3_price_oracle: Account<'info, PriceUpdateV2>,
+    /// Approval of the AI Agent mint's transfer hook program, for pools restricting hooks
+    #[account(
+        seeds = [TRANSFER_HOOK_APPROVAL_SEEDS_PREFIX, ai_agent_hook_approval.program_id.as_ref()],
+        bump = ai_agent_hook_approval.bump,
+    )]
+    pub ai_agent_hook_approval: Option<Box<Account<'info, TransferHookApproval>>>,
 
-    pub token_program_2022: Interface<'info, TokenInterface>,
+    /// Launch buy record of the user, required for buys inside the launch window when a per-wallet cap is set
+    /// CHECK: PDA checked by seeds, created in `apply` only when a buy has to be recorded
+    #[account(
+        mut,
+        seeds = [LAUNCH_BUY_RECORD_SEEDS_PREFIX, swap_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub launch_buy_record: Option<UncheckedAccount<'info>>,
+
+    /// Token program owning the AIW3 mint (SPL Token or Token-2022)
+    pub aiw3_token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the AI Agent mint (SPL Token or Token-2022)
+    pub ai_agent_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-impl Swap<'_> {
-    pub fn apply(ctx: &mut Context<Swap>, params: &SwapParams) -> Result<()> {
+impl<'info> Swap<'info> {
+    // remaining_accounts: extra accounts required by the mints' transfer hooks
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, Swap<'info>>,
+        params: &SwapParams,
+    ) -> Result<()> {
+        if let Some(deadline) = params.deadline {
+            require!(
+                !deadline.has_passed(&Clock::get()?),
+                PumpError::SwapDeadlineExceeded
+            );
+        }
+
+        // Native SOL output is unwrapped to the user, it cannot go to a recipient
+        require!(
+            !(params.native_sol && !params.input_is_aiw3 && params.recipient.is_some()),
+            PumpError::InvalidSwapParams
+        );
+        require!(
+            ctx.accounts.native_sol_account.is_some() == params.native_sol,
+            PumpError::InvalidSwapParams
+        );
+
+        if params.native_sol {
+            require!(
+                crate::utils::is_native_mint(&ctx.accounts.aiw3_token_mint.key()),
+                PumpError::NativeSolNotSupported
+            );
+
+            // Wrap the input lamports before anything reads the wSOL balance
+            if params.input_is_aiw3 {
+                require!(
+                    params.amount_in >= MINIMUM_SOL_BUY_AMOUNT,
+                    PumpError::AmountTooSmall
+                );
+
+                let native_sol_account = ctx
+                    .accounts
+                    .native_sol_account
+                    .as_mut()
+                    .ok_or(PumpError::NativeSolNotSupported)?;
+                crate::utils::wrap_native_sol(
+                    ctx.accounts.system_program.to_account_info(),
+                    &ctx.accounts.aiw3_token_program,
+                    ctx.accounts.user.to_account_info(),
+                    native_sol_account.to_account_info(),
+                    params.amount_in,
+                )?;
+                native_sol_account.reload()?;
+            }
+        }
+
         let swap_pool = &mut ctx.accounts.swap_pool;
         
         // Reentrancy protection
@@ -114,7 +216,7 @@ impl Swap<'_> {
         );
 
         require!(
-            params.amount_in >= MINIMUM_SWAP_AMOUNT,
+            params.amount_in >= swap_pool.min_swap_amount,
             PumpError::AmountTooSmall
         );
 
@@ -124,17 +226,34 @@ impl Swap<'_> {
             PumpError::AmountTooSmall
         );
 
-        require!(
-            params.minimum_amount_out <= params.amount_in * 2, // Reasonable slippage bounds
-            PumpError::InvalidSwapParams
-        );
+        // Hooks can be changed by the mint authority after the pool was created
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.aiw3_token_mint.to_account_info(),
+            swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.aiw3_hook_approval,
+        )?;
+        crate::utils::check_transfer_hook(
+            &ctx.accounts.ai_agent_token_mint.to_account_info(),
+            swap_pool.restrict_transfer_hooks,
+            &ctx.accounts.ai_agent_hook_approval,
+        )?;
+
+        // The first trade after the start time opens the launch window
+        let clock = Clock::get()?;
+        swap_pool.open_launch_window(clock.unix_timestamp, clock.slot)?;
 
         // Lock the pool to prevent reentrancy
         swap_pool.is_locked = true;
 
+        // Keep the recorded transfer fee in line with the mint
+        swap_pool.refresh_transfer_fee(clock.epoch);
+
         // Validate user has sufficient balance before attempting swap
         let user_balance = if params.input_is_aiw3 {
-            ctx.accounts.user_aiw3_account.amount
+            match &ctx.accounts.native_sol_account {
+                Some(native_sol_account) => native_sol_account.amount,
+                None => ctx.accounts.user_aiw3_account.amount,
+            }
         } else {
             ctx.accounts.user_ai_agent_account.amount
         };
@@ -144,14 +263,19 @@ impl Swap<'_> {
             PumpError::InsufficientBalance
         );
 
-        // Get AW3 token price from Pyth oracle
-        let aw3_price = get_aw3_price(&ctx.accounts.aw3_price_oracle)?;
+        // Get AW3 token price from Pyth oracle, weighted pools price from reserves
+        let aw3_price = crate::utils::get_pool_aw3_price(
+            swap_pool,
+            ctx.accounts.aw3_price_oracle.as_ref(),
+            ctx.accounts.config.oracle_max_age(),
+        )?;
 
         // Calculate swap output using the new pool logic
         let amount_out = swap_pool.calculate_swap_output(
             params.amount_in,
             params.input_is_aiw3,
             aw3_price,
+            clock.slot,
         )?;
 
         require!(
@@ -159,41 +283,55 @@ impl Swap<'_> {
             PumpError::InsufficientAmountOut
         );
 
+        // Buys inside the launch window are capped per transaction and per wallet
+        if params.input_is_aiw3 && swap_pool.in_launch_window(clock.slot) {
+            require!(
+                swap_pool.max_buy_per_tx == 0 || amount_out <= swap_pool.max_buy_per_tx,
+                PumpError::LaunchBuyLimitExceeded
+            );
+
+            if swap_pool.max_buy_per_wallet > 0 {
+                Self::record_launch_buy(ctx, amount_out)?;
+            }
+        }
+
+        let (recipient, output_account) = Self::output_account(ctx, params)?;
+
         // Perform the token transfers based on swap direction
         let result = if params.input_is_aiw3 {
             // AIW3 -> AI Agent Token
-            Self::transfer_aiw3_to_agent(ctx, params.amount_in, amount_out)
+            Self::transfer_aiw3_to_agent(ctx, params.amount_in, amount_out, output_account)
         } else {
             // AI Agent Token -> AIW3
-            Self::transfer_agent_to_aiw3(ctx, params.amount_in, amount_out)
+            Self::transfer_agent_to_aiw3(ctx, params.amount_in, amount_out, output_account)
         };
 
         // Always unlock the pool, even if transfer fails
+        let swap_pool = &mut ctx.accounts.swap_pool;
         swap_pool.is_locked = false;
 
         // Check if transfers were successful
         result?;
 
         // Update reserves only after successful transfers
-        if params.input_is_aiw3 {
-            swap_pool.aiw3_reserves = swap_pool.aiw3_reserves
-                .checked_add(params.amount_in)
-                .ok_or(PumpError::MathOverflow)?;
-            swap_pool.ai_agent_reserves = swap_pool.ai_agent_reserves
-                .checked_sub(amount_out)
-                .ok_or(PumpError::InsufficientLiquidity)?;
-        } else {
-            swap_pool.ai_agent_reserves = swap_pool.ai_agent_reserves
-                .checked_add(params.amount_in)
-                .ok_or(PumpError::MathOverflow)?;
-            swap_pool.aiw3_reserves = swap_pool.aiw3_reserves
-                .checked_sub(amount_out)
-                .ok_or(PumpError::InsufficientLiquidity)?;
-        }
+        swap_pool.record_swap(params.input_is_aiw3, params.amount_in, amount_out)?;
 
         // Update timestamp
         swap_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
 
+        // Close the temporary account, which holds nothing but the output and
+        // its own rent, or only the rent once the wrapped input was paid in
+        if let Some(native_sol_account) = &ctx.accounts.native_sol_account {
+            close_account(CpiContext::new(
+                ctx.accounts.aiw3_token_program.to_account_info(),
+                CloseAccount {
+                    account: native_sol_account.to_account_info(),
+                    destination: ctx.accounts.user.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ))?;
+        }
+
         // Emit swap event
         emit!(SwapEvent {
             user: ctx.accounts.user.key(),
@@ -203,160 +341,225 @@ impl Swap<'_> {
             amount_out,
             input_is_aiw3: params.input_is_aiw3,
             aw3_price,
-            pool_type: swap_pool.pool_type.clone(),
+            pool_type: format!("{:?}", swap_pool.pool_type),
+            recipient,
+            deadline: params.deadline,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
+    /// Add a buy to the user's launch buy record, creating the record on the
+    /// first buy, and enforce the per-wallet cap
+    fn record_launch_buy(
+        ctx: &Context<'_, '_, '_, 'info, Swap<'info>>,
+        amount_out: u64,
+    ) -> Result<()> {
+        let launch_buy_record_info = ctx
+            .accounts
+            .launch_buy_record
+            .as_ref()
+            .ok_or(PumpError::LaunchBuyRecordRequired)?
+            .to_account_info();
+        let swap_pool_key = ctx.accounts.swap_pool.key();
+        let user_key = ctx.accounts.user.key();
+        let bump = ctx.bumps.launch_buy_record;
+
+        let mut launch_buy_record = if launch_buy_record_info.owner == &crate::ID {
+            LaunchBuyRecord::try_deserialize(&mut &launch_buy_record_info.try_borrow_data()?[..])?
+        } else {
+            let record_signer_seeds = &[
+                LAUNCH_BUY_RECORD_SEEDS_PREFIX,
+                swap_pool_key.as_ref(),
+                user_key.as_ref(),
+                &[bump],
+            ];
+
+            crate::utils::create_pda_account(
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.user.to_account_info(),
+                launch_buy_record_info.clone(),
+                8 + LaunchBuyRecord::INIT_SPACE,
+                &[record_signer_seeds],
+            )?;
+
+            LaunchBuyRecord {
+                swap_pool: swap_pool_key,
+                wallet: user_key,
+                amount_bought: 0,
+                bump,
+            }
+        };
+
+        launch_buy_record.amount_bought = launch_buy_record
+            .amount_bought
+            .checked_add(amount_out)
+            .ok_or(PumpError::MathOverflow)?;
+
+        require!(
+            launch_buy_record.amount_bought <= ctx.accounts.swap_pool.max_buy_per_wallet,
+            PumpError::LaunchBuyLimitExceeded
+        );
+
+        let mut data = launch_buy_record_info.try_borrow_mut_data()?;
+        launch_buy_record.try_serialize(&mut &mut data[..])
+    }
+
+    /// Resolve where the output goes: the recipient's account when one is
+    /// set, otherwise the user's own account for the output side
+    fn output_account(
+        ctx: &Context<'_, '_, '_, 'info, Swap<'info>>,
+        params: &SwapParams,
+    ) -> Result<(Pubkey, AccountInfo<'info>)> {
+        let Some(recipient) = params.recipient else {
+            let output_account = if params.input_is_aiw3 {
+                ctx.accounts.user_ai_agent_account.to_account_info()
+            } else if params.native_sol {
+                ctx.accounts
+                    .native_sol_account
+                    .as_ref()
+                    .ok_or(PumpError::NativeSolNotSupported)?
+                    .to_account_info()
+            } else {
+                ctx.accounts.user_aiw3_account.to_account_info()
+            };
+            return Ok((ctx.accounts.user.key(), output_account));
+        };
+
+        let recipient_output_account = ctx
+            .accounts
+            .recipient_output_account
+            .as_ref()
+            .ok_or(PumpError::InvalidRecipient)?;
+        let output_mint = if params.input_is_aiw3 {
+            ctx.accounts.ai_agent_token_mint.key()
+        } else {
+            ctx.accounts.aiw3_token_mint.key()
+        };
+
+        require_keys_eq!(
+            recipient_output_account.owner,
+            recipient,
+            PumpError::InvalidRecipient
+        );
+        require_keys_eq!(
+            recipient_output_account.mint,
+            output_mint,
+            PumpError::InvalidTokenMint
+        );
+
+        Ok((recipient, recipient_output_account.to_account_info()))
+    }
+
     fn transfer_aiw3_to_agent(
-        ctx: &Context<Swap>,
+        ctx: &Context<'_, '_, '_, 'info, Swap<'info>>,
         aiw3_amount_in: u64,
         agent_amount_out: u64,
+        output_account: AccountInfo<'info>,
     ) -> Result<()> {
         let swap_pool = &ctx.accounts.swap_pool;
 
-        // Calculate transfer fees
-        let aiw3_transfer_fee = calculate_transfer_fee_from_mint(
+        // Transfer AIW3 from user to pool, native SOL was wrapped into the temporary account
+        let input_account = match &ctx.accounts.native_sol_account {
+            Some(native_sol_account) => native_sol_account.to_account_info(),
+            None => ctx.accounts.user_aiw3_account.to_account_info(),
+        };
+        transfer_tokens(
+            &ctx.accounts.aiw3_token_program,
+            input_account,
             &ctx.accounts.aiw3_token_mint,
+            ctx.accounts.pool_aiw3_vault.to_account_info(),
+            ctx.accounts.user.to_account_info(),
             aiw3_amount_in,
-        )?;
-        
-        let agent_transfer_fee = calculate_transfer_fee_from_mint(
-            &ctx.accounts.ai_agent_token_mint,
-            agent_amount_out,
-        )?;
-
-        // Transfer AIW3 from user to pool
-        transfer_checked_with_fee(
-            CpiContext::new(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferCheckedWithFee {
-                    from: ctx.accounts.user_aiw3_account.to_account_info(),
-                    mint: ctx.accounts.aiw3_token_mint.to_account_info(),
-                    to: ctx.accounts.pool_aiw3_vault.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            aiw3_amount_in,
-            ctx.accounts.aiw3_token_mint.decimals,
-            aiw3_transfer_fee,
+            &[],
+            ctx.remaining_accounts,
         )?;
 
-        // Transfer AI Agent tokens from pool to user
+        // Transfer AI Agent tokens from pool to the output account
+        let aiw3_token_mint_key = ctx.accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = ctx.accounts.ai_agent_token_mint.key();
         let pool_signer_seeds = &[
             SWAP_POOL_SEEDS_PREFIX,
-            ctx.accounts.aiw3_token_mint.key().as_ref(),
-            ctx.accounts.ai_agent_token_mint.key().as_ref(),
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
             &[swap_pool.bump],
         ];
 
-        transfer_checked_with_fee(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferCheckedWithFee {
-                    from: ctx.accounts.pool_ai_agent_vault.to_account_info(),
-                    mint: ctx.accounts.ai_agent_token_mint.to_account_info(),
-                    to: ctx.accounts.user_ai_agent_account.to_account_info(),
-                    authority: swap_pool.to_account_info(),
-                },
-                &[pool_signer_seeds],
-            ),
+        transfer_tokens(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.pool_ai_agent_vault.to_account_info(),
+            &ctx.accounts.ai_agent_token_mint,
+            output_account,
+            swap_pool.to_account_info(),
             agent_amount_out,
-            ctx.accounts.ai_agent_token_mint.decimals,
-            agent_transfer_fee,
+            &[pool_signer_seeds],
+            ctx.remaining_accounts,
         )?;
 
         Ok(())
     }
 
     fn transfer_agent_to_aiw3(
-        ctx: &Context<Swap>,
+        ctx: &Context<'_, '_, '_, 'info, Swap<'info>>,
         agent_amount_in: u64,
         aiw3_amount_out: u64,
+        output_account: AccountInfo<'info>,
     ) -> Result<()> {
         let swap_pool = &ctx.accounts.swap_pool;
 
-        // Calculate transfer fees
-        let agent_transfer_fee = calculate_transfer_fee_from_mint(
-            &ctx.accounts.ai_agent_token_mint,
-            agent_amount_in,
-        )?;
-        
-        let aiw3_transfer_fee = calculate_transfer_fee_from_mint(
-            &ctx.accounts.aiw3_token_mint,
-            aiw3_amount_out,
-        )?;
-
         // Transfer AI Agent tokens from user to pool
-        transfer_checked_with_fee(
-            CpiContext::new(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferCheckedWithFee {
-                    from: ctx.accounts.user_ai_agent_account.to_account_info(),
-                    mint: ctx.accounts.ai_agent_token_mint.to_account_info(),
-                    to: ctx.accounts.pool_ai_agent_vault.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
+        transfer_tokens(
+            &ctx.accounts.ai_agent_token_program,
+            ctx.accounts.user_ai_agent_account.to_account_info(),
+            &ctx.accounts.ai_agent_token_mint,
+            ctx.accounts.pool_ai_agent_vault.to_account_info(),
+            ctx.accounts.user.to_account_info(),
             agent_amount_in,
-            ctx.accounts.ai_agent_token_mint.decimals,
-            agent_transfer_fee,
+            &[],
+            ctx.remaining_accounts,
         )?;
 
-        // Transfer AIW3 from pool to user
+        // Transfer AIW3 from pool to the output account
+        let aiw3_token_mint_key = ctx.accounts.aiw3_token_mint.key();
+        let ai_agent_token_mint_key = ctx.accounts.ai_agent_token_mint.key();
         let pool_signer_seeds = &[
             SWAP_POOL_SEEDS_PREFIX,
-            ctx.accounts.aiw3_token_mint.key().as_ref(),
-            ctx.accounts.ai_agent_token_mint.key().as_ref(),
+            aiw3_token_mint_key.as_ref(),
+            ai_agent_token_mint_key.as_ref(),
             &[swap_pool.bump],
         ];
 
-        transfer_checked_with_fee(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_2022.to_account_info(),
-                TransferCheckedWithFee {
-                    from: ctx.accounts.pool_aiw3_vault.to_account_info(),
-                    mint: ctx.accounts.aiw3_token_mint.to_account_info(),
-                    to: ctx.accounts.user_aiw3_account.to_account_info(),
-                    authority: swap_pool.to_account_info(),
-                },
-                &[pool_signer_seeds],
-            ),
+        transfer_tokens(
+            &ctx.accounts.aiw3_token_program,
+            ctx.accounts.pool_aiw3_vault.to_account_info(),
+            &ctx.accounts.aiw3_token_mint,
+            output_account,
+            swap_pool.to_account_info(),
             aiw3_amount_out,
-            ctx.accounts.aiw3_token_mint.decimals,
-            aiw3_transfer_fee,
+            &[pool_signer_seeds],
+            ctx.remaining_accounts,
         )?;
 
         Ok(())
     }
 }
 
-fn get_aw3_price(price_oracle: &Account<PriceUpdateV2>) -> Result<i64> {
-    use crate::utils::get_validated_price;
-    
-    // Use the validated price function with proper feed ID verification
-    let price = get_validated_price(price_oracle, AW3_FEED_ID, MAXIMUM_AGE)?;
-    
-    // Additional validation: ensure price is positive and reasonable
-    require!(
-        price > 0,
-        PumpError::InvalidPriceOracle
-    );
-    
-    // Add bounds check for price (adjust these based on expected AW3 price range)
-    require!(
-        price >= 1_000 && price <= 1_000_000_000, // $0.01 to $10,000 range
-        PumpError::InvalidPriceOracle
-    );
-    
-    Ok(price)
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-fn calculate_transfer_fee_from_mint(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
-    // This should extract the actual transfer fee from the Token 2022 mint
-    // For now, using a simplified approach
-    use crate::utils::calculate_transfer_fee;
-    calculate_transfer_fee(&mint.to_account_info(), amount)
-} 
\ No newline at end of file
+    #[test]
+    fn deadline_includes_its_last_timestamp_and_slot() {
+        let clock = Clock {
+            slot: 100,
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+
+        assert!(!SwapDeadline::Timestamp(1_000).has_passed(&clock));
+        assert!(SwapDeadline::Timestamp(999).has_passed(&clock));
+        assert!(!SwapDeadline::Slot(100).has_passed(&clock));
+        assert!(SwapDeadline::Slot(99).has_passed(&clock));
+    }
+}
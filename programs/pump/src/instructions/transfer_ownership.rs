@@ -19,9 +19,19 @@ impl TransferOwnership<'_> {
         ctx: &mut Context<TransferOwnership>,
         params: TransferOwnershipParams,
     ) -> Result<()> {
-        ctx.accounts.config.admin = params.new_admin;
+        require!(
+            params.new_admin != Pubkey::default() && params.new_admin != ctx.accounts.config.admin,
+            PumpError::InvalidOwner
+        );
 
-        msg!("transfer ownership to {}", ctx.accounts.config.admin,);
+        // The new admin only takes over once they accept
+        ctx.accounts.config.pending_admin = params.new_admin;
+
+        emit!(OwnershipTransferProposedEvent {
+            admin: ctx.accounts.config.admin,
+            pending_admin: params.new_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
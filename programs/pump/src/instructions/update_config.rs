@@ -4,50 +4,277 @@ use crate::*;
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
+    /// Admin, or a fee or oracle manager for their settings
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
     #[account(mut,
         seeds = [CONFIG_SEEDS_PREFIX],
         bump = config.bump,
-        has_one = admin @ PumpError::NotAdmin,
     )]
     pub config: Box<Account<'info, Config>>,
 }
 
 impl UpdateConfig<'_> {
     pub fn apply(ctx: &mut Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
-        if let Some(fee_recipient) = params.fee_recipient {
-            ctx.accounts.config.fee_recipient = fee_recipient;
+        // Immediate updates are only available while no timelock is configured
+        require!(
+            ctx.accounts.config.config_update_delay == 0,
+            PumpError::TimelockRequired
+        );
+
+        params.authorize(
+            &ctx.accounts.config,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.role_account,
+        )?;
+
+        params.apply_to(&mut ctx.accounts.config, ctx.accounts.authority.key())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub struct UpdateConfigParams {
+    pub fee_recipient: Option<Pubkey>,
+    pub operator: Option<Pubkey>,
+    pub guardian: Option<Pubkey>,
+    pub allow_creator_pool_updates: Option<bool>,
+    pub trade_fee_basis_points: Option<u16>,
+    pub pump_fee: Option<u64>,
+    pub config_update_delay: Option<i64>,
+    pub max_price_age: Option<u64>,
+}
+
+impl UpdateConfigParams {
+    /// Admins may change anything, fee managers only the fee amounts and
+    /// oracle managers only the price age. Where fees go stays admin-only.
+    pub fn authorize(
+        &self,
+        config: &Config,
+        authority: &Pubkey,
+        role_account: &Option<Box<Account<RoleAccount>>>,
+    ) -> Result<()> {
+        if *authority == config.admin {
+            return Ok(());
+        }
+
+        require!(
+            self.fee_recipient.is_none()
+                && self.operator.is_none()
+                && self.guardian.is_none()
+                && self.allow_creator_pool_updates.is_none()
+                && self.config_update_delay.is_none(),
+            PumpError::NotAdmin
+        );
+
+        let updates_fees = self.trade_fee_basis_points.is_some() || self.pump_fee.is_some();
+        let updates_oracle = self.max_price_age.is_some();
+        require!(updates_fees || updates_oracle, PumpError::MissingRole);
+        require!(
+            !updates_fees || crate::utils::has_role(role_account, ROLE_FEE_MANAGER),
+            PumpError::MissingRole
+        );
+        require!(
+            !updates_oracle || crate::utils::has_role(role_account, ROLE_ORACLE_MANAGER),
+            PumpError::MissingRole
+        );
+
+        Ok(())
+    }
+
+    /// Applies the update, validates the result and emits `ConfigUpdatedEvent`
+    pub fn apply_to(&self, config: &mut Config, authority: Pubkey) -> Result<()> {
+        self.patch(config);
+        config.validate()?;
+
+        emit!(ConfigUpdatedEvent {
+            authority,
+            fee_recipient: config.fee_recipient,
+            operator: config.operator,
+            guardian: config.guardian,
+            allow_creator_pool_updates: config.allow_creator_pool_updates,
+            trade_fee_basis_points: config.trade_fee_basis_points,
+            pump_fee: config.pump_fee,
+            config_update_delay: config.config_update_delay,
+            max_price_age: config.max_price_age,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Writes the fields set in the update into `config`, without validating
+    pub fn patch(&self, config: &mut Config) {
+        if let Some(fee_recipient) = self.fee_recipient {
+            config.fee_recipient = fee_recipient;
         }
 
-        if let Some(operator) = params.operator {
-            ctx.accounts.config.operator = operator;
+        if let Some(operator) = self.operator {
+            config.operator = operator;
         }
 
-        if let Some(trade_fee_basis_points) = params.trade_fee_basis_points {
-            ctx.accounts.config.trade_fee_basis_points = trade_fee_basis_points;
+        if let Some(guardian) = self.guardian {
+            config.guardian = guardian;
         }
 
-        if let Some(pump_fee) = params.pump_fee {
-            ctx.accounts.config.pump_fee = pump_fee;
+        if let Some(allow_creator_pool_updates) = self.allow_creator_pool_updates {
+            config.allow_creator_pool_updates = allow_creator_pool_updates;
         }
 
-        msg!(
-            "Config updated: fee_recipient {}, operator {}, trade_fee_basis_points {}, pump_fee {}",
-            ctx.accounts.config.fee_recipient,
-            ctx.accounts.config.operator,
-            ctx.accounts.config.trade_fee_basis_points,
-            ctx.accounts.config.pump_fee
-        );
+        if let Some(trade_fee_basis_points) = self.trade_fee_basis_points {
+            config.trade_fee_basis_points = trade_fee_basis_points;
+        }
 
-        Ok(())
+        if let Some(pump_fee) = self.pump_fee {
+            config.pump_fee = pump_fee;
+        }
+
+        if let Some(config_update_delay) = self.config_update_delay {
+            config.config_update_delay = config_update_delay;
+        }
+
+        if let Some(max_price_age) = self.max_price_age {
+            config.max_price_age = max_price_age;
+        }
     }
 }
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
-pub struct UpdateConfigParams {
-    pub fee_recipient: Option<Pubkey>,
-    pub operator: Option<Pubkey>,
-    pub trade_fee_basis_points: Option<u16>,
-    pub pump_fee: Option<u64>,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> UpdateConfigParams {
+        UpdateConfigParams {
+            fee_recipient: None,
+            operator: None,
+            guardian: None,
+            allow_creator_pool_updates: None,
+            trade_fee_basis_points: None,
+            pump_fee: None,
+            config_update_delay: None,
+            max_price_age: None,
+        }
+    }
+
+    /// Role account for `member`, leaked so it can back an `Account`
+    fn role_account(member: Pubkey, roles: u8) -> Option<Box<Account<'static, RoleAccount>>> {
+        let mut data = Vec::new();
+        RoleAccount { member, roles, bump: 255 }
+            .try_serialize(&mut data)
+            .unwrap();
+
+        let info = Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            data.leak(),
+            &crate::ID,
+            false,
+            0,
+        )));
+
+        Some(Box::new(Account::try_from(info).unwrap()))
+    }
+
+    #[test]
+    fn admin_may_update_anything() {
+        let config = Config::test_config();
+        let update = UpdateConfigParams {
+            fee_recipient: Some(Pubkey::new_unique()),
+            trade_fee_basis_points: Some(50),
+            max_price_age: Some(60),
+            ..params()
+        };
+
+        assert!(update.authorize(&config, &config.admin, &None).is_ok());
+    }
+
+    #[test]
+    fn fee_managers_may_only_update_fee_amounts() {
+        let config = Config::test_config();
+        let authority = Pubkey::new_unique();
+        let fee_manager = role_account(authority, ROLE_FEE_MANAGER);
+
+        let fees = UpdateConfigParams {
+            trade_fee_basis_points: Some(50),
+            pump_fee: Some(0),
+            ..params()
+        };
+        assert!(fees.authorize(&config, &authority, &fee_manager).is_ok());
+        assert!(fees.authorize(&config, &authority, &None).is_err());
+
+        let fee_recipient = UpdateConfigParams {
+            fee_recipient: Some(authority),
+            ..params()
+        };
+        assert!(fee_recipient.authorize(&config, &authority, &fee_manager).is_err());
+
+        let max_price_age = UpdateConfigParams {
+            max_price_age: Some(60),
+            ..params()
+        };
+        assert!(max_price_age.authorize(&config, &authority, &fee_manager).is_err());
+    }
+
+    #[test]
+    fn oracle_managers_may_only_update_the_price_age() {
+        let config = Config::test_config();
+        let authority = Pubkey::new_unique();
+        let oracle_manager = role_account(authority, ROLE_ORACLE_MANAGER);
+
+        let max_price_age = UpdateConfigParams {
+            max_price_age: Some(60),
+            ..params()
+        };
+        assert!(max_price_age.authorize(&config, &authority, &oracle_manager).is_ok());
+
+        let fees = UpdateConfigParams {
+            trade_fee_basis_points: Some(50),
+            ..params()
+        };
+        assert!(fees.authorize(&config, &authority, &oracle_manager).is_err());
+    }
+
+    #[test]
+    fn empty_updates_need_admin() {
+        let config = Config::test_config();
+        let authority = Pubkey::new_unique();
+
+        assert!(params()
+            .authorize(&config, &authority, &role_account(authority, ALL_ROLES))
+            .is_err());
+    }
+
+    #[test]
+    fn patch_only_writes_the_fields_set() {
+        let config = Config::test_config();
+        let mut patched = Config::clone(&config);
+        UpdateConfigParams {
+            trade_fee_basis_points: Some(50),
+            ..params()
+        }
+        .patch(&mut patched);
+
+        assert_eq!(patched.trade_fee_basis_points, 50);
+        assert_eq!(patched.fee_recipient, config.fee_recipient);
+        assert_eq!(patched.max_price_age, config.max_price_age);
+    }
+
+    #[test]
+    fn patched_configs_are_validated_before_queueing() {
+        let mut patched = Config::test_config();
+        UpdateConfigParams {
+            config_update_delay: Some(MAX_CONFIG_UPDATE_DELAY + 1),
+            ..params()
+        }
+        .patch(&mut patched);
+
+        assert!(patched.validate().is_err());
+    }
 }
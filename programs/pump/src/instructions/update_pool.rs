@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdatePool<'info> {
+    /// Admin, pool manager, or the pool creator when allowed by config
+    pub authority: Signer<'info>,
+
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_POOL_SEEDS_PREFIX, swap_pool.aiw3_token_mint.as_ref(), swap_pool.ai_agent_token_mint.as_ref()],
+        bump = swap_pool.bump,
+        constraint = authority.key() == config.admin
+            || crate::utils::has_role(&role_account, ROLE_POOL_MANAGER)
+            || (config.allow_creator_pool_updates && authority.key() == swap_pool.creator) @ PumpError::InvalidOwner,
+    )]
+    pub swap_pool: Box<Account<'info, SwapPool>>,
+}
+
+impl UpdatePool<'_> {
+    pub fn apply(ctx: &mut Context<UpdatePool>, params: UpdatePoolParams) -> Result<()> {
+        let swap_pool = &mut ctx.accounts.swap_pool;
+
+        let old_swap_fee_basis_points = swap_pool.swap_fee_basis_points;
+        let old_max_price_impact_basis_points = swap_pool.max_price_impact_basis_points;
+        let old_min_swap_amount = swap_pool.min_swap_amount;
+
+        if let Some(swap_fee_basis_points) = params.swap_fee_basis_points {
+            require!(
+                swap_fee_basis_points <= MAX_SWAP_FEE_BASIS_POINTS,
+                PumpError::InvalidSwapParams
+            );
+            swap_pool.swap_fee_basis_points = swap_fee_basis_points;
+        }
+
+        if let Some(max_price_impact_basis_points) = params.max_price_impact_basis_points {
+            require!(
+                max_price_impact_basis_points > 0 && max_price_impact_basis_points <= 10000,
+                PumpError::InvalidSwapParams
+            );
+            swap_pool.max_price_impact_basis_points = max_price_impact_basis_points;
+        }
+
+        if let Some(min_swap_amount) = params.min_swap_amount {
+            require!(
+                min_swap_amount > 0,
+                PumpError::InvalidSwapParams
+            );
+            swap_pool.min_swap_amount = min_swap_amount;
+        }
+
+        // Launch protections can only change before the first trade
+        if let Some(launch_protection) = &params.launch_protection {
+            require!(
+                swap_pool.launch_slot == 0,
+                PumpError::InvalidLaunchProtection
+            );
+            let swap_pool_key = swap_pool.key();
+            launch_protection.apply_to(swap_pool, swap_pool_key, Clock::get()?.unix_timestamp)?;
+        }
+
+        swap_pool.last_update_timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(PoolUpdatedEvent {
+            authority: ctx.accounts.authority.key(),
+            swap_pool: swap_pool.key(),
+            old_swap_fee_basis_points,
+            new_swap_fee_basis_points: swap_pool.swap_fee_basis_points,
+            old_max_price_impact_basis_points,
+            new_max_price_impact_basis_points: swap_pool.max_price_impact_basis_points,
+            old_min_swap_amount,
+            new_min_swap_amount: swap_pool.min_swap_amount,
+            timestamp: swap_pool.last_update_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePoolParams {
+    pub swap_fee_basis_points: Option<u16>,
+    pub max_price_impact_basis_points: Option<u16>,
+    pub min_swap_amount: Option<u64>,
+    pub launch_protection: Option<LaunchProtectionParams>,
+}
@@ -0,0 +1,82 @@
+use anchor_spl::{
+    token_2022::{spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_mint, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawTransferFees<'info> {
+    /// Admin or fee manager
+    pub authority: Signer<'info>,
+
+    /// Role account of the authority, required when it is not the admin
+    #[account(
+        seeds = [ROLE_SEEDS_PREFIX, authority.key().as_ref()],
+        bump = role_account.bump,
+    )]
+    pub role_account: Option<Box<Account<'info, RoleAccount>>>,
+
+    /// Withdraw-withheld authority set on mints by `create_token`
+    #[account(
+        seeds = [CONFIG_SEEDS_PREFIX],
+        bump = config.bump,
+        constraint = authority.key() == config.admin
+            || crate::utils::has_role(&role_account, ROLE_FEE_MANAGER) @ PumpError::NotAdmin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut, mint::token_program = token_program_2022)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Fee recipient's token account for `mint`
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program_2022,
+        constraint = fee_recipient_token_account.owner == config.fee_recipient @ PumpError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+impl WithdrawTransferFees<'_> {
+    pub fn apply(ctx: &mut Context<WithdrawTransferFees>) -> Result<()> {
+        let amount = crate::utils::get_transfer_fee_config(&ctx.accounts.mint.to_account_info())?
+            .map(|config| u64::from(config.withheld_amount))
+            .ok_or(PumpError::InvalidTokenMint)?;
+
+        require!(amount > 0, PumpError::NothingToClaim);
+
+        let withdraw_ix = withdraw_withheld_tokens_from_mint(
+            &ctx.accounts.token_program_2022.key(),
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.fee_recipient_token_account.key(),
+            &ctx.accounts.config.key(),
+            &[],
+        )?;
+
+        let config_signer_seeds = &[CONFIG_SEEDS_PREFIX, &[ctx.accounts.config.bump]];
+
+        solana_program::program::invoke_signed(
+            &withdraw_ix,
+            &[
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.fee_recipient_token_account.to_account_info(),
+                ctx.accounts.config.to_account_info(),
+            ],
+            &[config_signer_seeds],
+        )?;
+
+        emit!(TransferFeesWithdrawnEvent {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            destination: ctx.accounts.fee_recipient_token_account.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
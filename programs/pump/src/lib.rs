@@ -6,10 +6,11 @@ use crate::events::*;
 use crate::instructions::*;
 use crate::states::*;
 
-mod constants;
+pub mod constants;
 mod errors;
-mod events;
+pub mod events;
 mod instructions;
+mod math;
 mod states;
 mod utils;
 
@@ -23,15 +24,46 @@ pub mod pump {
         Initialize::apply(&mut ctx, &params)
     }
 
-    pub fn create_token(mut ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<()> {
+    pub fn create_token<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CreateToken<'info>>,
+        params: CreateTokenParams,
+    ) -> Result<()> {
         CreateToken::apply(&mut ctx, &params)
     }
 
-    pub fn create_pool(mut ctx: Context<CreatePool>, params: CreatePoolParams) -> Result<()> {
+    pub fn create_pool<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CreatePool<'info>>,
+        params: CreatePoolParams,
+    ) -> Result<()> {
         CreatePool::apply(&mut ctx, &params)
     }
 
-    pub fn swap(mut ctx: Context<Swap>, params: SwapParams) -> Result<()> {
+    pub fn close_pool<'info>(mut ctx: Context<'_, '_, '_, 'info, ClosePool<'info>>) -> Result<()> {
+        ClosePool::apply(&mut ctx)
+    }
+
+    pub fn add_liquidity<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        params: AddLiquidityParams,
+    ) -> Result<()> {
+        AddLiquidity::apply(&mut ctx, &params)
+    }
+
+    pub fn remove_liquidity<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+        params: RemoveLiquidityParams,
+    ) -> Result<()> {
+        RemoveLiquidity::apply(&mut ctx, &params)
+    }
+
+    pub fn quote(mut ctx: Context<Quote>, params: QuoteParams) -> Result<u64> {
+        Quote::apply(&mut ctx, &params)
+    }
+
+    pub fn swap<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        params: SwapParams,
+    ) -> Result<()> {
         Swap::apply(&mut ctx, &params)
     }
 
@@ -39,10 +71,165 @@ pub mod pump {
         UpdateConfig::apply(&mut ctx, params)
     }
 
+    pub fn queue_config_update(
+        mut ctx: Context<QueueConfigUpdate>,
+        params: UpdateConfigParams,
+    ) -> Result<()> {
+        QueueConfigUpdate::apply(&mut ctx, params)
+    }
+
+    pub fn execute_config_update(mut ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+        ExecuteConfigUpdate::apply(&mut ctx)
+    }
+
+    pub fn cancel_config_update(mut ctx: Context<CancelConfigUpdate>) -> Result<()> {
+        CancelConfigUpdate::apply(&mut ctx)
+    }
+
+    pub fn set_paused(mut ctx: Context<SetPaused>, params: SetPausedParams) -> Result<()> {
+        SetPaused::apply(&mut ctx, params)
+    }
+
+    pub fn set_pool_status(
+        mut ctx: Context<SetPoolStatus>,
+        params: SetPoolStatusParams,
+    ) -> Result<()> {
+        SetPoolStatus::apply(&mut ctx, params)
+    }
+
+    pub fn update_pool(mut ctx: Context<UpdatePool>, params: UpdatePoolParams) -> Result<()> {
+        UpdatePool::apply(&mut ctx, params)
+    }
+
     pub fn transfer_ownership(
         mut ctx: Context<TransferOwnership>,
         params: TransferOwnershipParams,
     ) -> Result<()> {
         TransferOwnership::apply(&mut ctx, params)
     }
+
+    pub fn accept_ownership(mut ctx: Context<AcceptOwnership>) -> Result<()> {
+        AcceptOwnership::apply(&mut ctx)
+    }
+
+    pub fn cancel_ownership_transfer(mut ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        CancelOwnershipTransfer::apply(&mut ctx)
+    }
+
+    pub fn grant_role(mut ctx: Context<GrantRole>, params: GrantRoleParams) -> Result<()> {
+        GrantRole::apply(&mut ctx, params)
+    }
+
+    pub fn revoke_role(mut ctx: Context<RevokeRole>, params: RevokeRoleParams) -> Result<()> {
+        RevokeRole::apply(&mut ctx, params)
+    }
+
+    pub fn migrate_config(mut ctx: Context<MigrateConfig>) -> Result<()> {
+        MigrateConfig::apply(&mut ctx)
+    }
+
+    pub fn migrate_pool(mut ctx: Context<MigratePool>) -> Result<()> {
+        MigratePool::apply(&mut ctx)
+    }
+
+    pub fn create_vesting_schedule<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CreateVestingSchedule<'info>>,
+        params: CreateVestingScheduleParams,
+    ) -> Result<()> {
+        CreateVestingSchedule::apply(&mut ctx, &params)
+    }
+
+    pub fn claim_vested<'info>(mut ctx: Context<'_, '_, '_, 'info, ClaimVested<'info>>) -> Result<()> {
+        ClaimVested::apply(&mut ctx)
+    }
+
+    pub fn revoke_vesting<'info>(mut ctx: Context<'_, '_, '_, 'info, RevokeVesting<'info>>) -> Result<()> {
+        RevokeVesting::apply(&mut ctx)
+    }
+
+    pub fn burn_treasury(mut ctx: Context<BurnTreasury>, params: BurnTreasuryParams) -> Result<()> {
+        BurnTreasury::apply(&mut ctx, &params)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        HarvestTransferFees::apply(&mut ctx)
+    }
+
+    pub fn withdraw_transfer_fees(mut ctx: Context<WithdrawTransferFees>) -> Result<()> {
+        WithdrawTransferFees::apply(&mut ctx)
+    }
+
+    pub fn set_transfer_fee(mut ctx: Context<SetTransferFee>, params: SetTransferFeeParams) -> Result<()> {
+        SetTransferFee::apply(&mut ctx, &params)
+    }
+
+    pub fn approve_transfer_hook(
+        mut ctx: Context<ApproveTransferHook>,
+        params: ApproveTransferHookParams,
+    ) -> Result<()> {
+        ApproveTransferHook::apply(&mut ctx, params)
+    }
+
+    pub fn revoke_transfer_hook(mut ctx: Context<RevokeTransferHook>) -> Result<()> {
+        RevokeTransferHook::apply(&mut ctx)
+    }
+
+    pub fn place_limit_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, PlaceLimitOrder<'info>>,
+        params: PlaceLimitOrderParams,
+    ) -> Result<()> {
+        PlaceLimitOrder::apply(&mut ctx, &params)
+    }
+
+    pub fn fill_limit_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, FillLimitOrder<'info>>,
+    ) -> Result<()> {
+        FillLimitOrder::apply(&mut ctx)
+    }
+
+    pub fn cancel_limit_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CancelLimitOrder<'info>>,
+    ) -> Result<()> {
+        CancelLimitOrder::apply(&mut ctx)
+    }
+
+    pub fn open_dca_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, OpenDcaOrder<'info>>,
+        params: OpenDcaOrderParams,
+    ) -> Result<()> {
+        OpenDcaOrder::apply(&mut ctx, &params)
+    }
+
+    pub fn execute_dca_slice<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, ExecuteDcaSlice<'info>>,
+    ) -> Result<()> {
+        ExecuteDcaSlice::apply(&mut ctx)
+    }
+
+    pub fn close_dca_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CloseDcaOrder<'info>>,
+    ) -> Result<()> {
+        CloseDcaOrder::apply(&mut ctx)
+    }
+
+    pub fn place_trigger_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, PlaceTriggerOrder<'info>>,
+        params: PlaceTriggerOrderParams,
+    ) -> Result<()> {
+        PlaceTriggerOrder::apply(&mut ctx, &params)
+    }
+
+    pub fn execute_trigger_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, ExecuteTriggerOrder<'info>>,
+    ) -> Result<()> {
+        ExecuteTriggerOrder::apply(&mut ctx)
+    }
+
+    pub fn cancel_trigger_order<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, CancelTriggerOrder<'info>>,
+    ) -> Result<()> {
+        CancelTriggerOrder::apply(&mut ctx)
+    }
 }
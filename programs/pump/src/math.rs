@@ -0,0 +1,221 @@
+use crate::PumpError;
+
+/// Fixed-point scale used by the weighted pool math (18 decimal places)
+pub const ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Stop the power series once terms drop below this value
+const POW_PRECISION: u128 = 100_000_000;
+
+/// Hard cap on series iterations to keep compute usage bounded
+const MAX_POW_ITERATIONS: u128 = 64;
+
+/// Worst-case error of `fixed_pow` for bases in the pools' range [2/3, 1]:
+/// the dropped tail of the series stays below POW_PRECISION, the rounding
+/// of the fixed-point steps adds a few hundred units at most
+const POW_ERROR_MARGIN: u128 = POW_PRECISION;
+
+pub fn fixed_mul(a: u128, b: u128) -> Result<u128, PumpError> {
+    a.checked_mul(b)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_add(ONE / 2)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_div(ONE)
+        .ok_or(PumpError::MathOverflow)
+}
+
+pub fn fixed_div(a: u128, b: u128) -> Result<u128, PumpError> {
+    if b == 0 {
+        return Err(PumpError::MathOverflow);
+    }
+
+    a.checked_mul(ONE)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_add(b / 2)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_div(b)
+        .ok_or(PumpError::MathOverflow)
+}
+
+/// Like `fixed_div`, but rounding up
+pub fn fixed_div_up(a: u128, b: u128) -> Result<u128, PumpError> {
+    if b == 0 {
+        return Err(PumpError::MathOverflow);
+    }
+
+    a.checked_mul(ONE)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_add(b - 1)
+        .ok_or(PumpError::MathOverflow)?
+        .checked_div(b)
+        .ok_or(PumpError::MathOverflow)
+}
+
+/// Raise a fixed-point base to a whole number power
+fn fixed_pow_int(base: u128, exp: u128) -> Result<u128, PumpError> {
+    let mut base = base;
+    let mut exp = exp;
+    let mut result = ONE;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = fixed_mul(result, base)?;
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = fixed_mul(base, base)?;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Approximate base^exp for a fractional exponent using the binomial series
+/// of (1 - x)^exp, where x = 1 - base. Only valid for base <= ONE.
+fn fixed_pow_frac(base: u128, exp: u128) -> Result<u128, PumpError> {
+    let x = ONE.checked_sub(base).ok_or(PumpError::MathOverflow)?;
+
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut negative = false;
+    let mut i: u128 = 1;
+
+    while term >= POW_PRECISION && i <= MAX_POW_ITERATIONS {
+        let big_k = i.checked_mul(ONE).ok_or(PumpError::MathOverflow)?;
+        let k_minus_one = big_k - ONE;
+        let (c, c_negative) = if exp >= k_minus_one {
+            (exp - k_minus_one, false)
+        } else {
+            (k_minus_one - exp, true)
+        };
+
+        term = fixed_mul(term, fixed_mul(c, x)?)?;
+        term = fixed_div(term, big_k)?;
+        if term == 0 {
+            break;
+        }
+
+        // x is subtracted from one, so every term flips sign once more
+        negative = !negative;
+        if c_negative {
+            negative = !negative;
+        }
+
+        sum = if negative {
+            sum.checked_sub(term).ok_or(PumpError::MathOverflow)?
+        } else {
+            sum.checked_add(term).ok_or(PumpError::MathOverflow)?
+        };
+
+        i += 1;
+    }
+
+    Ok(sum)
+}
+
+/// Raise a fixed-point base in (0, ONE] to a fixed-point exponent
+pub fn fixed_pow(base: u128, exp: u128) -> Result<u128, PumpError> {
+    if base == 0 || base > ONE {
+        return Err(PumpError::MathOverflow);
+    }
+
+    let whole = exp / ONE;
+    let remain = exp % ONE;
+
+    let whole_pow = fixed_pow_int(base, whole)?;
+    if remain == 0 {
+        return Ok(whole_pow);
+    }
+
+    let partial = fixed_pow_frac(base, remain)?;
+    fixed_mul(whole_pow, partial)
+}
+
+/// `fixed_pow` rounded up past its worst-case error, so that `ONE - power`
+/// never exceeds the exact result
+pub fn fixed_pow_up(base: u128, exp: u128) -> Result<u128, PumpError> {
+    Ok(fixed_pow(base, exp)?.saturating_add(POW_ERROR_MARGIN).min(ONE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_fixed(value: f64) -> u128 {
+        (value * ONE as f64) as u128
+    }
+
+    fn to_f64(value: u128) -> f64 {
+        value as f64 / ONE as f64
+    }
+
+    #[test]
+    fn fixed_div_up_rounds_up() {
+        assert_eq!(fixed_div(2, 3).unwrap(), 666_666_666_666_666_667);
+        assert_eq!(fixed_div_up(1, 3).unwrap(), 333_333_333_333_333_334);
+        assert_eq!(fixed_div_up(1, 2).unwrap(), ONE / 2);
+    }
+
+    #[test]
+    fn fixed_pow_handles_trivial_exponents() {
+        let base = to_fixed(0.8);
+
+        assert_eq!(fixed_pow(base, 0).unwrap(), ONE);
+        assert_eq!(fixed_pow(base, ONE).unwrap(), base);
+        assert_eq!(fixed_pow(ONE, to_fixed(0.37)).unwrap(), ONE);
+        assert_eq!(fixed_pow(ONE / 2, 2 * ONE).unwrap(), ONE / 4);
+    }
+
+    #[test]
+    fn fixed_pow_rejects_bases_outside_the_unit_interval() {
+        assert!(fixed_pow(0, ONE).is_err());
+        assert!(fixed_pow(ONE + 1, ONE).is_err());
+    }
+
+    #[test]
+    fn fixed_pow_frac_converges_across_the_pool_range() {
+        // Bases from 2/3 (input at half the reserves) up to just below one
+        for base in [2.0 / 3.0, 0.75, 0.9, 0.99, 0.999_999] {
+            for exp in [0.020_408_163_265_306_12, 0.25, 0.5, 0.75, 0.999] {
+                let result = fixed_pow(to_fixed(base), to_fixed(exp)).unwrap();
+                let expected = base.powf(exp);
+
+                assert!(
+                    (to_f64(result) - expected).abs() < 1e-9,
+                    "{base}^{exp}: {} vs {expected}",
+                    to_f64(result)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_pow_handles_extreme_weight_ratios() {
+        // 9800/200 and 200/9800, the widest weights a pool accepts
+        let heavy = fixed_div(9800, 200).unwrap();
+        let light = fixed_div(200, 9800).unwrap();
+        assert_eq!(heavy, 49 * ONE);
+        assert_eq!(light, 20_408_163_265_306_122);
+
+        let base = 2.0 / 3.0;
+        let heavy_result = fixed_pow(to_fixed(base), heavy).unwrap();
+        let light_result = fixed_pow(to_fixed(base), light).unwrap();
+
+        assert!((to_f64(heavy_result) - base.powi(49)).abs() < 1e-12);
+        assert!((to_f64(light_result) - base.powf(200.0 / 9800.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_pow_up_never_rounds_below_the_exact_power() {
+        for base in [2.0 / 3.0, 0.8, 0.95, 0.999_9] {
+            for exp in [to_fixed(200.0 / 9800.0), ONE / 2, 3 * ONE / 2, 49 * ONE] {
+                let result = fixed_pow_up(to_fixed(base), exp).unwrap();
+                let expected = base.powf(to_f64(exp));
+
+                assert!(to_f64(result) >= expected, "{base}^{}", to_f64(exp));
+                assert!(to_f64(result) - expected < 1e-9);
+            }
+        }
+
+        assert_eq!(fixed_pow_up(ONE, ONE / 2).unwrap(), ONE);
+    }
+}
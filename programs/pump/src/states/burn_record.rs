@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct BurnRecord {
+    pub mint: Pubkey,
+    pub total_burned: u64,            // Cumulative amount burned by the protocol
+    pub last_burn_timestamp: i64,
+    pub bump: u8,
+}
+
+impl BurnRecord {
+    /// Add a burn of `amount` to the running total, which may not pass `burn_limit`
+    pub fn record_burn(
+        &mut self,
+        amount: u64,
+        burn_limit: u64,
+        now: i64,
+    ) -> std::result::Result<(), crate::PumpError> {
+        let total_burned = self
+            .total_burned
+            .checked_add(amount)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        if total_burned > burn_limit {
+            return Err(crate::PumpError::BurnLimitExceeded);
+        }
+
+        self.total_burned = total_burned;
+        self.last_burn_timestamp = now;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn burn_record() -> BurnRecord {
+        BurnRecord {
+            mint: Pubkey::default(),
+            total_burned: 0,
+            last_burn_timestamp: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn burns_accumulate_per_record() {
+        let mut record = burn_record();
+
+        record.record_burn(1_000, crate::BURN_AMOUNT, 10).unwrap();
+        record.record_burn(2_500, crate::BURN_AMOUNT, 20).unwrap();
+
+        assert_eq!(record.total_burned, 3_500);
+        assert_eq!(record.last_burn_timestamp, 20);
+    }
+
+    #[test]
+    fn burns_stop_at_the_burn_amount() {
+        let mut record = burn_record();
+
+        record.record_burn(crate::BURN_AMOUNT - 1, crate::BURN_AMOUNT, 10).unwrap();
+        assert!(record.record_burn(2, crate::BURN_AMOUNT, 20).is_err());
+        assert_eq!(record.total_burned, crate::BURN_AMOUNT - 1);
+        assert_eq!(record.last_burn_timestamp, 10);
+
+        record.record_burn(1, crate::BURN_AMOUNT, 30).unwrap();
+        assert_eq!(record.total_burned, crate::BURN_AMOUNT);
+    }
+}
@@ -11,13 +11,159 @@ pub struct Config {
 
     pub pump_fee: u64,
     pub mop_mint: Pubkey,
-    pub _padding: [u8; 100],
+
+    pub is_paused: bool,
+    pub guardian: Pubkey,
+    pub allow_creator_pool_updates: bool,
+    pub pending_admin: Pubkey,
+    pub config_update_delay: i64,
+    pub version: u8, // Account layout version, reads 0 on configs created before versioning
+    pub max_price_age: u64, // Oldest AW3 price accepted, in seconds
+    pub _padding: [u8; 17],
 }
 
 impl Config {
+    /// Layout version, counting unversioned configs as layout 1
+    pub fn layout_version(&self) -> u8 {
+        self.version.max(crate::LEGACY_ACCOUNT_VERSION)
+    }
+
+    /// Oldest AW3 price accepted, in seconds. Configs not yet migrated to
+    /// layout 3 read 0 and use `MAXIMUM_AGE`.
+    pub fn oracle_max_age(&self) -> u64 {
+        if self.max_price_age == 0 {
+            crate::MAXIMUM_AGE
+        } else {
+            self.max_price_age
+        }
+    }
+
+    /// Checks the invariants every config write must uphold
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.trade_fee_basis_points <= crate::MAX_SWAP_FEE_BASIS_POINTS,
+            crate::PumpError::InvalidFeeBasisPoints
+        );
+        require!(
+            self.pump_fee <= crate::MAX_PUMP_FEE,
+            crate::PumpError::InvalidPumpFee
+        );
+        require!(
+            self.fee_recipient != Pubkey::default(),
+            crate::PumpError::InvalidFeeRecipient
+        );
+        require!(
+            self.operator != Pubkey::default(),
+            crate::PumpError::InvalidOperator
+        );
+        require!(
+            (0..=crate::MAX_CONFIG_UPDATE_DELAY).contains(&self.config_update_delay),
+            crate::PumpError::InvalidTimelockDelay
+        );
+        require!(
+            (1..=crate::MAXIMUM_AGE).contains(&self.max_price_age),
+            crate::PumpError::InvalidPriceOracle
+        );
+
+        Ok(())
+    }
+
     pub fn trade_fee(&self, amount: u64) -> u64 {
         amount
             .saturating_mul(self.trade_fee_basis_points as u64)
             .saturating_div(10000)
     }
 }
+
+#[cfg(test)]
+impl Config {
+    /// A config that passes `validate`, for unit tests
+    pub fn test_config() -> Self {
+        Config {
+            admin: Pubkey::new_unique(),
+            fee_recipient: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            bump: 255,
+            trade_fee_basis_points: crate::DEFAULT_TRADE_FEE_BASIS_POINTS,
+            pump_fee: 0,
+            mop_mint: Pubkey::default(),
+            is_paused: false,
+            guardian: Pubkey::default(),
+            allow_creator_pool_updates: false,
+            pending_admin: Pubkey::default(),
+            config_update_delay: 0,
+            version: crate::CONFIG_VERSION,
+            max_price_age: crate::MAXIMUM_AGE,
+            _padding: [0; 17],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_layouts_keep_the_account_size() {
+        // Size of the baseline layout, fields added since are carved out of the padding
+        assert_eq!(8 + Config::INIT_SPACE, 247);
+    }
+
+    #[test]
+    fn unversioned_configs_count_as_layout_one() {
+        let mut config = Config::test_config();
+        config.version = 0;
+        assert_eq!(config.layout_version(), crate::LEGACY_ACCOUNT_VERSION);
+
+        config.version = crate::CONFIG_VERSION;
+        assert_eq!(config.layout_version(), crate::CONFIG_VERSION);
+    }
+
+    #[test]
+    fn validate_enforces_config_bounds() {
+        assert!(Config::test_config().validate().is_ok());
+
+        let mut config = Config::test_config();
+        config.trade_fee_basis_points = crate::MAX_SWAP_FEE_BASIS_POINTS + 1;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::test_config();
+        config.pump_fee = crate::MAX_PUMP_FEE + 1;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::test_config();
+        config.fee_recipient = Pubkey::default();
+        assert!(config.validate().is_err());
+
+        let mut config = Config::test_config();
+        config.operator = Pubkey::default();
+        assert!(config.validate().is_err());
+
+        let mut config = Config::test_config();
+        config.config_update_delay = -1;
+        assert!(config.validate().is_err());
+        config.config_update_delay = crate::MAX_CONFIG_UPDATE_DELAY;
+        assert!(config.validate().is_ok());
+        config.config_update_delay = crate::MAX_CONFIG_UPDATE_DELAY + 1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_bounds_the_oracle_price_age() {
+        let mut config = Config::test_config();
+        config.max_price_age = 0;
+        assert!(config.validate().is_err());
+        config.max_price_age = crate::MAXIMUM_AGE + 1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn unmigrated_configs_use_the_default_price_age() {
+        let mut config = Config::test_config();
+        config.max_price_age = 0;
+        assert_eq!(config.oracle_max_age(), crate::MAXIMUM_AGE);
+
+        config.max_price_age = 60;
+        assert_eq!(config.oracle_max_age(), 60);
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct DcaOrder {
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub order_id: u64,                // Owner-chosen id, part of the order seeds
+
+    pub total_amount: u64,            // AIW3 escrowed (net of transfer fees)
+    pub slice_amount: u64,            // AIW3 sold per execution
+    pub min_price: u64,               // Minimum AI Agent out per AIW3 in, scaled by PRICE_PRECISION
+    pub interval_seconds: i64,
+    pub next_execution_timestamp: i64,
+
+    pub filled_amount_in: u64,        // AIW3 sold so far
+    pub filled_amount_out: u64,       // AI Agent tokens bought so far
+    pub slices_executed: u32,
+    pub created_timestamp: i64,
+
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl DcaOrder {
+    pub fn remaining_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.filled_amount_in)
+    }
+
+    /// Size of the next slice, the last one takes whatever is left
+    pub fn next_slice_amount(&self) -> u64 {
+        self.slice_amount.min(self.remaining_amount())
+    }
+
+    /// Per-slice slippage bound derived from `min_price`
+    pub fn min_amount_out(&self, amount_in: u64) -> std::result::Result<u64, crate::PumpError> {
+        let amount_out = (amount_in as u128)
+            .checked_mul(self.min_price as u128)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(crate::PRICE_PRECISION as u128)
+            .ok_or(crate::PumpError::MathOverflow)?;
+
+        u64::try_from(amount_out).map_err(|_| crate::PumpError::MathOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order() -> DcaOrder {
+        DcaOrder {
+            owner: Pubkey::default(),
+            swap_pool: Pubkey::default(),
+            order_id: 0,
+            total_amount: 1_000,
+            slice_amount: 300,
+            min_price: 2 * crate::PRICE_PRECISION,
+            interval_seconds: crate::MIN_DCA_INTERVAL,
+            next_execution_timestamp: 0,
+            filled_amount_in: 0,
+            filled_amount_out: 0,
+            slices_executed: 0,
+            created_timestamp: 0,
+            bump: 255,
+            vault_bump: 255,
+        }
+    }
+
+    #[test]
+    fn last_slice_takes_the_remainder() {
+        let mut order = order();
+        assert_eq!(order.next_slice_amount(), 300);
+
+        order.filled_amount_in = 900;
+        assert_eq!(order.remaining_amount(), 100);
+        assert_eq!(order.next_slice_amount(), 100);
+
+        order.filled_amount_in = 1_000;
+        assert_eq!(order.next_slice_amount(), 0);
+    }
+
+    #[test]
+    fn min_amount_out_follows_min_price() {
+        let order = order();
+
+        assert_eq!(order.min_amount_out(300).unwrap(), 600);
+        assert!(order.min_amount_out(u64::MAX).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchBuyRecord {
+    pub swap_pool: Pubkey,
+    pub wallet: Pubkey,
+    pub amount_bought: u64,           // AI Agent tokens bought inside the launch window
+    pub bump: u8,
+}
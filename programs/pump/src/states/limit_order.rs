@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_is_aiw3: bool,
+
+    pub order_id: u64,                // Owner-chosen id, part of the order seeds
+    pub amount_in: u64,               // Escrowed input (net of transfer fees)
+    pub target_price: u64,            // Minimum output per input, scaled by PRICE_PRECISION
+    pub min_amount_out: u64,          // Output owed to the owner after the filler tip
+    pub expiry_timestamp: i64,        // Order can no longer be filled after this time
+    pub created_timestamp: i64,
+
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl LimitOrder {
+    /// Output owed to the owner for `amount_in` at `target_price`
+    pub fn target_amount_out(amount_in: u64, target_price: u64) -> std::result::Result<u64, crate::PumpError> {
+        let amount_out = (amount_in as u128)
+            .checked_mul(target_price as u128)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(crate::PRICE_PRECISION as u128)
+            .ok_or(crate::PumpError::MathOverflow)?;
+
+        u64::try_from(amount_out).map_err(|_| crate::PumpError::MathOverflow)
+    }
+
+    /// Filler tip taken from the pool output
+    pub fn tip(amount_out: u64) -> u64 {
+        amount_out
+            .saturating_mul(crate::LIMIT_ORDER_TIP_BASIS_POINTS as u64)
+            .saturating_div(10000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_amount_out_scales_by_price_precision() {
+        assert_eq!(LimitOrder::target_amount_out(1_000, crate::PRICE_PRECISION).unwrap(), 1_000);
+        assert_eq!(LimitOrder::target_amount_out(1_000, 2_500_000).unwrap(), 2_500);
+        assert_eq!(LimitOrder::target_amount_out(3, 500_000).unwrap(), 1);
+        assert!(LimitOrder::target_amount_out(u64::MAX, 2 * crate::PRICE_PRECISION).is_err());
+    }
+
+    #[test]
+    fn tip_is_a_fraction_of_the_output() {
+        assert_eq!(LimitOrder::tip(1_000_000), 1_000);
+        assert_eq!(LimitOrder::tip(999), 0);
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityPosition {
+    pub swap_pool: Pubkey,
+    pub owner: Pubkey,
+    pub shares: u64,                  // Claim on the pool reserves, out of `SwapPool::total_shares`
+    pub locked_shares: u64,           // Creator's initial liquidity, only released by `close_pool`
+    pub bump: u8,
+}
@@ -1,5 +1,25 @@
+pub use burn_record::*;
 pub use config::*;
+pub use dca_order::*;
+pub use launch_buy_record::*;
+pub use limit_order::*;
+pub use liquidity_position::*;
+pub use pending_config_update::*;
+pub use role::*;
 pub use swap_pool::*;
+pub use transfer_hook_approval::*;
+pub use trigger_order::*;
+pub use vesting_schedule::*;
 
+mod burn_record;
 mod config;
+mod dca_order;
+mod launch_buy_record;
+mod limit_order;
+mod liquidity_position;
+mod pending_config_update;
+mod role;
 mod swap_pool;
+mod transfer_hook_approval;
+mod trigger_order;
+mod vesting_schedule;
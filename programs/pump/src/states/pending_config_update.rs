@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::UpdateConfigParams;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigUpdate {
+    pub proposer: Pubkey,
+    pub payer: Pubkey,      // Paid the rent, refunded when the update is executed or cancelled
+    pub params: UpdateConfigParams,
+    pub eta: i64,           // Earliest time the update can be executed
+    pub bump: u8,
+}
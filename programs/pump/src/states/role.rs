@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAccount {
+    pub member: Pubkey,
+    pub roles: u8, // Bitmask of ROLE_* flags
+    pub bump: u8,
+}
+
+impl RoleAccount {
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & role == role
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_role_requires_every_requested_flag() {
+        let role_account = RoleAccount {
+            member: Pubkey::default(),
+            roles: crate::ROLE_FEE_MANAGER | crate::ROLE_PAUSER,
+            bump: 255,
+        };
+
+        assert!(role_account.has_role(crate::ROLE_FEE_MANAGER));
+        assert!(role_account.has_role(crate::ROLE_PAUSER));
+        assert!(!role_account.has_role(crate::ROLE_ORACLE_MANAGER));
+        assert!(!role_account.has_role(crate::ROLE_FEE_MANAGER | crate::ROLE_ORACLE_MANAGER));
+    }
+}
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct SwapPool {
+    pub version: u8,                  // Account layout version
     pub creator: Pubkey,
     pub aiw3_token_mint: Pubkey,      // AIW3 Token (platform token)
     pub ai_agent_token_mint: Pubkey,  // AI Agent Token (project token)
@@ -15,33 +16,277 @@ pub struct SwapPool {
     pub swap_fee_basis_points: u16,   // Swap fee in basis points
     pub aiw3_transfer_fee_basis_points: u16,  // AIW3 transfer fee
     pub ai_agent_transfer_fee_basis_points: u16, // AI Agent transfer fee
+    pub max_price_impact_basis_points: u16, // Max share of output reserves per swap
+    pub min_swap_amount: u64,         // Minimum input amount per swap
     
     // Pool state
     pub is_active: bool,
     pub is_locked: bool,              // Reentrancy protection
     pub pool_type: PoolType,          // Internal or External pool
+    pub curve_type: CurveType,        // Pricing curve used by the pool
+    pub aiw3_weight: u16,             // AIW3 side weight in basis points
+    pub ai_agent_weight: u16,         // AI Agent side weight in basis points
     pub created_timestamp: i64,       // Pool creation time
     pub last_update_timestamp: i64,   // Last price update
     pub bump: u8,
+
+    // Scheduled AI Agent transfer fee change (Token-2022 activates it two epochs later)
+    pub pending_ai_agent_transfer_fee_basis_points: u16,
+    pub transfer_fee_activation_epoch: u64, // 0 when nothing is scheduled
+
+    pub restrict_transfer_hooks: bool, // Only admin-approved transfer hook programs may be used
+
+    // Launch protection, limits buys of the AI Agent token right after launch
+    pub trading_start_timestamp: i64, // No trades before this time
+    pub launch_slot: u64,             // Slot of the first trade, 0 until trading opens
+    pub launch_protection_slots: u64, // Length of the protection window, 0 disables it
+    pub max_buy_per_tx: u64,          // AI Agent tokens per buy inside the window, 0 for no cap
+    pub max_buy_per_wallet: u64,      // AI Agent tokens per wallet inside the window, 0 for no cap
+    pub launch_fee_basis_points: u16, // Swap fee at launch, decays to swap_fee_basis_points
+
+    pub total_shares: u64,            // Liquidity shares outstanding, weighted pools only
+
+    pub _padding: [u8; 3],            // Reserved for future fields
+}
+
+/// Layout of pools created before account versioning, read by `migrate_pool`
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct SwapPoolV1 {
+    pub creator: Pubkey,
+    pub aiw3_token_mint: Pubkey,
+    pub ai_agent_token_mint: Pubkey,
+    pub aiw3_reserves: u64,
+    pub ai_agent_reserves: u64,
+    pub swap_fee_basis_points: u16,
+    pub aiw3_transfer_fee_basis_points: u16,
+    pub ai_agent_transfer_fee_basis_points: u16,
+    pub is_active: bool,
+    pub is_locked: bool,
+    pub pool_type: PoolType,
+    pub created_timestamp: i64,
+    pub last_update_timestamp: i64,
+    pub bump: u8,
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+#[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub enum PoolType {
     Internal,  // Internal DEX pool (A1)
     External,  // External DEX pool (A2) 
 }
 
+impl PoolType {
+    /// Name used for the pool type in events
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoolType::Internal => crate::INTERNAL_POOL_TYPE,
+            PoolType::External => crate::EXTERNAL_POOL_TYPE,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum CurveType {
+    Oracle,          // Priced from the AW3 oracle feed
+    WeightedProduct, // Weighted constant-product invariant
+}
+
 impl SwapPool {
     pub const DISCRIMINATOR_SIZE: usize = 8;
     pub const SIZE: usize = Self::DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+    pub const V1_SIZE: usize = Self::DISCRIMINATOR_SIZE + SwapPoolV1::INIT_SPACE;
+
+    /// Upgrade a legacy pool, filling the fields it did not have with defaults
+    pub fn from_v1(pool: SwapPoolV1) -> Self {
+        Self {
+            version: crate::SWAP_POOL_VERSION,
+            creator: pool.creator,
+            aiw3_token_mint: pool.aiw3_token_mint,
+            ai_agent_token_mint: pool.ai_agent_token_mint,
+            aiw3_reserves: pool.aiw3_reserves,
+            ai_agent_reserves: pool.ai_agent_reserves,
+            swap_fee_basis_points: pool.swap_fee_basis_points,
+            aiw3_transfer_fee_basis_points: pool.aiw3_transfer_fee_basis_points,
+            ai_agent_transfer_fee_basis_points: pool.ai_agent_transfer_fee_basis_points,
+            max_price_impact_basis_points: crate::MAX_PRICE_IMPACT,
+            min_swap_amount: crate::MINIMUM_SWAP_AMOUNT,
+            is_active: pool.is_active,
+            is_locked: pool.is_locked,
+            pool_type: pool.pool_type,
+            curve_type: CurveType::Oracle,
+            aiw3_weight: crate::DEFAULT_TOKEN_WEIGHT,
+            ai_agent_weight: crate::DEFAULT_TOKEN_WEIGHT,
+            created_timestamp: pool.created_timestamp,
+            last_update_timestamp: pool.last_update_timestamp,
+            bump: pool.bump,
+            pending_ai_agent_transfer_fee_basis_points: 0,
+            transfer_fee_activation_epoch: 0,
+            restrict_transfer_hooks: false,
+            trading_start_timestamp: 0,
+            launch_slot: 0,
+            launch_protection_slots: 0,
+            max_buy_per_tx: 0,
+            max_buy_per_wallet: 0,
+            launch_fee_basis_points: 0,
+            total_shares: 0,
+            _padding: [0; 3],
+        }
+    }
     
-    /// Calculate swap output based on reserves and fees
+    /// Apply a scheduled transfer fee change once its activation epoch is reached
+    pub fn refresh_transfer_fee(&mut self, epoch: u64) {
+        if self.transfer_fee_activation_epoch != 0 && epoch >= self.transfer_fee_activation_epoch {
+            self.ai_agent_transfer_fee_basis_points = self.pending_ai_agent_transfer_fee_basis_points;
+            self.transfer_fee_activation_epoch = 0;
+        }
+    }
+
+    /// Move a completed swap into the reserves
+    pub fn record_swap(
+        &mut self,
+        input_is_aiw3: bool,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> std::result::Result<(), crate::PumpError> {
+        let (reserve_in, reserve_out) = if input_is_aiw3 {
+            (&mut self.aiw3_reserves, &mut self.ai_agent_reserves)
+        } else {
+            (&mut self.ai_agent_reserves, &mut self.aiw3_reserves)
+        };
+
+        *reserve_in = reserve_in
+            .checked_add(amount_in)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        *reserve_out = reserve_out
+            .checked_sub(amount_out)
+            .ok_or(crate::PumpError::InsufficientLiquidity)?;
+
+        Ok(())
+    }
+
+    /// Token amounts backing `shares`, rounded up for deposits and down for
+    /// withdrawals so rounding always favours the pool
+    pub fn liquidity_for_shares(
+        &self,
+        shares: u64,
+        round_up: bool,
+    ) -> std::result::Result<(u64, u64), crate::PumpError> {
+        if shares == 0 {
+            return Err(crate::PumpError::AmountTooSmall);
+        }
+        if self.total_shares == 0 {
+            return Err(crate::PumpError::InsufficientLiquidity);
+        }
+
+        let total_shares = self.total_shares as u128;
+        let amount_for = |reserve: u64| {
+            let numerator = (reserve as u128)
+                .checked_mul(shares as u128)
+                .ok_or(crate::PumpError::MathOverflow)?;
+            let amount = if round_up {
+                numerator.div_ceil(total_shares)
+            } else {
+                numerator / total_shares
+            };
+            u64::try_from(amount).map_err(|_| crate::PumpError::MathOverflow)
+        };
+
+        Ok((amount_for(self.aiw3_reserves)?, amount_for(self.ai_agent_reserves)?))
+    }
+
+    /// Issue `shares` against a proportional deposit, returns the AIW3 and
+    /// AI Agent amounts to deposit
+    pub fn add_liquidity(&mut self, shares: u64) -> std::result::Result<(u64, u64), crate::PumpError> {
+        let (aiw3_amount, ai_agent_amount) = self.liquidity_for_shares(shares, true)?;
+
+        self.aiw3_reserves = self
+            .aiw3_reserves
+            .checked_add(aiw3_amount)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        self.ai_agent_reserves = self
+            .ai_agent_reserves
+            .checked_add(ai_agent_amount)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        self.total_shares = self
+            .total_shares
+            .checked_add(shares)
+            .ok_or(crate::PumpError::MathOverflow)?;
+
+        Ok((aiw3_amount, ai_agent_amount))
+    }
+
+    /// Redeem `shares` for a proportional withdrawal, returns the AIW3 and
+    /// AI Agent amounts to withdraw. Unless every share is redeemed both
+    /// reserves stay above `MIN_POOL_RESERVES`
+    pub fn remove_liquidity(&mut self, shares: u64) -> std::result::Result<(u64, u64), crate::PumpError> {
+        if shares > self.total_shares {
+            return Err(crate::PumpError::InsufficientLiquidity);
+        }
+
+        let (aiw3_amount, ai_agent_amount) = self.liquidity_for_shares(shares, false)?;
+
+        self.aiw3_reserves -= aiw3_amount;
+        self.ai_agent_reserves -= ai_agent_amount;
+        self.total_shares -= shares;
+
+        if self.total_shares > 0
+            && (self.aiw3_reserves < crate::MIN_POOL_RESERVES
+                || self.ai_agent_reserves < crate::MIN_POOL_RESERVES)
+        {
+            return Err(crate::PumpError::InsufficientLiquidity);
+        }
+
+        Ok((aiw3_amount, ai_agent_amount))
+    }
+
+    /// Reject trades before the trading start and start the launch window
+    /// on the first trade after it
+    pub fn open_launch_window(&mut self, now: i64, slot: u64) -> std::result::Result<(), crate::PumpError> {
+        if now < self.trading_start_timestamp {
+            return Err(crate::PumpError::TradingNotStarted);
+        }
+
+        if self.launch_slot == 0 {
+            self.launch_slot = slot;
+        }
+
+        Ok(())
+    }
+
+    /// Whether launch protections apply at `slot`
+    pub fn in_launch_window(&self, slot: u64) -> bool {
+        self.launch_protection_slots > 0
+            && (self.launch_slot == 0
+                || slot < self.launch_slot.saturating_add(self.launch_protection_slots))
+    }
+
+    /// Swap fee at `slot`, decaying linearly from the launch fee to the
+    /// regular fee over the launch window
+    pub fn swap_fee_basis_points_at(&self, slot: u64) -> u16 {
+        if !self.in_launch_window(slot) || self.launch_fee_basis_points <= self.swap_fee_basis_points {
+            return self.swap_fee_basis_points;
+        }
+
+        let elapsed = if self.launch_slot == 0 {
+            0
+        } else {
+            slot.saturating_sub(self.launch_slot)
+        };
+        let fee_range = (self.launch_fee_basis_points - self.swap_fee_basis_points) as u64;
+        let decayed = fee_range
+            .saturating_mul(elapsed)
+            .saturating_div(self.launch_protection_slots);
+
+        self.launch_fee_basis_points - decayed.min(fee_range) as u16
+    }
+
+    /// Calculate swap output based on reserves and fees at `slot`
     pub fn calculate_swap_output(
         &self,
         amount_in: u64,
         input_is_aiw3: bool,
         aw3_price: i64,
-    ) -> Result<u64, crate::PumpError> {
+        slot: u64,
+    ) -> std::result::Result<u64, crate::PumpError> {
         if !self.is_active {
             return Err(crate::PumpError::SwapPoolNotActive);
         }
@@ -51,14 +296,14 @@ impl SwapPool {
             return Err(crate::PumpError::InsufficientLiquidity);
         }
 
-        let (reserve_in, reserve_out) = if input_is_aiw3 {
-            (self.aiw3_reserves, self.ai_agent_reserves)
+        let reserve_out = if input_is_aiw3 {
+            self.ai_agent_reserves
         } else {
-            (self.ai_agent_reserves, self.aiw3_reserves)
+            self.aiw3_reserves
         };
 
         // Apply swap fee with overflow protection
-        let fee_multiplier = 10000u128.saturating_sub(self.swap_fee_basis_points as u128);
+        let fee_multiplier = 10000u128.saturating_sub(self.swap_fee_basis_points_at(slot) as u128);
         let amount_in_with_fee = (amount_in as u128)
             .checked_mul(fee_multiplier)
             .ok_or(crate::PumpError::MathOverflow)?
@@ -70,8 +315,10 @@ impl SwapPool {
             return Err(crate::PumpError::AmountTooSmall);
         }
 
-        // Price-based calculation using AW3 oracle price
-        let amount_out = if input_is_aiw3 {
+        let amount_out = if self.curve_type == CurveType::WeightedProduct {
+            // Invariant-based calculation using the pool weights
+            self.calculate_weighted_output(amount_in_with_fee as u64, input_is_aiw3)?
+        } else if input_is_aiw3 {
             // AIW3 -> AI Agent Token: use oracle price
             self.calculate_aiw3_to_agent(amount_in_with_fee as u64, aw3_price)?
         } else {
@@ -89,10 +336,68 @@ impl SwapPool {
             return Err(crate::PumpError::AmountTooSmall);
         }
 
+        // Limit how much of the output reserves a single swap can take
+        let max_amount_out = (reserve_out as u128)
+            .checked_mul(self.max_price_impact_basis_points as u128)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        if amount_out as u128 > max_amount_out {
+            return Err(crate::PumpError::PriceImpactTooHigh);
+        }
+
         Ok(amount_out)
     }
 
-    fn calculate_aiw3_to_agent(&self, aiw3_amount: u64, aw3_price: i64) -> Result<u64, crate::PumpError> {
+    /// Weighted product output:
+    /// out = reserve_out * (1 - (reserve_in / (reserve_in + amount_in)) ^ (weight_in / weight_out))
+    fn calculate_weighted_output(&self, amount_in: u64, input_is_aiw3: bool) -> std::result::Result<u64, crate::PumpError> {
+        use crate::math::{fixed_div, fixed_div_up, fixed_pow_up, ONE};
+
+        let (reserve_in, reserve_out, weight_in, weight_out) = if input_is_aiw3 {
+            (self.aiw3_reserves, self.ai_agent_reserves, self.aiw3_weight, self.ai_agent_weight)
+        } else {
+            (self.ai_agent_reserves, self.aiw3_reserves, self.ai_agent_weight, self.aiw3_weight)
+        };
+
+        if weight_in == 0 || weight_out == 0 {
+            return Err(crate::PumpError::InvalidPoolWeights);
+        }
+
+        // Keep the power series in its convergent range
+        let max_amount_in = (reserve_in as u128)
+            .checked_mul(crate::MAX_IN_RATIO_BASIS_POINTS as u128)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        if amount_in as u128 > max_amount_in {
+            return Err(crate::PumpError::InsufficientLiquidity);
+        }
+
+        let new_reserve_in = (reserve_in as u128)
+            .checked_add(amount_in as u128)
+            .ok_or(crate::PumpError::MathOverflow)?;
+        // Round the power up so the pool never pays out more than the invariant allows
+        let base = fixed_div_up(reserve_in as u128, new_reserve_in)?;
+        let exponent = fixed_div(weight_in as u128, weight_out as u128)?;
+        let power = fixed_pow_up(base, exponent)?;
+
+        let ratio_out = ONE.checked_sub(power).ok_or(crate::PumpError::MathOverflow)?;
+        let amount_out = (reserve_out as u128)
+            .checked_mul(ratio_out)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(ONE)
+            .ok_or(crate::PumpError::MathOverflow)?;
+
+        // Additional bounds check
+        if amount_out > u64::MAX as u128 {
+            return Err(crate::PumpError::MathOverflow);
+        }
+
+        Ok(amount_out as u64)
+    }
+
+    fn calculate_aiw3_to_agent(&self, aiw3_amount: u64, aw3_price: i64) -> std::result::Result<u64, crate::PumpError> {
         if aw3_price <= 0 {
             return Err(crate::PumpError::InvalidPriceOracle);
         }
@@ -117,7 +422,7 @@ impl SwapPool {
         Ok(usd_value as u64)
     }
 
-    fn calculate_agent_to_aiw3(&self, agent_amount: u64, aw3_price: i64) -> Result<u64, crate::PumpError> {
+    fn calculate_agent_to_aiw3(&self, agent_amount: u64, aw3_price: i64) -> std::result::Result<u64, crate::PumpError> {
         if aw3_price <= 0 {
             return Err(crate::PumpError::InvalidPriceOracle);
         }
@@ -140,4 +445,188 @@ impl SwapPool {
 
         Ok(aiw3_amount as u64)
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+impl SwapPool {
+    /// An active oracle pool without reserves or launch protection, for unit tests
+    pub fn test_pool() -> Self {
+        SwapPool::from_v1(SwapPoolV1 {
+            creator: Pubkey::default(),
+            aiw3_token_mint: Pubkey::default(),
+            ai_agent_token_mint: Pubkey::default(),
+            aiw3_reserves: 0,
+            ai_agent_reserves: 0,
+            swap_fee_basis_points: 30,
+            aiw3_transfer_fee_basis_points: 0,
+            ai_agent_transfer_fee_basis_points: 0,
+            is_active: true,
+            is_locked: false,
+            pool_type: PoolType::Internal,
+            created_timestamp: 0,
+            last_update_timestamp: 0,
+            bump: 255,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAUNCH_SLOT: u64 = 1_000;
+    const WINDOW: u64 = 100;
+
+    fn launch_pool() -> SwapPool {
+        let mut pool = SwapPool::test_pool();
+        pool.launch_protection_slots = WINDOW;
+        pool.launch_fee_basis_points = 1_030;
+        pool.launch_slot = LAUNCH_SLOT;
+        pool
+    }
+
+    fn weighted_pool(aiw3_weight: u16, ai_agent_weight: u16) -> SwapPool {
+        let mut pool = SwapPool::test_pool();
+        pool.curve_type = CurveType::WeightedProduct;
+        pool.aiw3_weight = aiw3_weight;
+        pool.ai_agent_weight = ai_agent_weight;
+        pool.aiw3_reserves = 1_000_000_000_000;
+        pool.ai_agent_reserves = 5_000_000_000_000;
+        pool
+    }
+
+    #[test]
+    fn weighted_output_never_exceeds_the_invariant() {
+        for (aiw3_weight, ai_agent_weight) in [(5000, 5000), (200, 9800), (9800, 200)] {
+            let pool = weighted_pool(aiw3_weight, ai_agent_weight);
+
+            for amount_in in [1_000_000u64, 1_000_000_000, 100_000_000_000, 500_000_000_000] {
+                let amount_out = pool.calculate_weighted_output(amount_in, true).unwrap();
+
+                let reserve_in = pool.aiw3_reserves as f64;
+                let reserve_out = pool.ai_agent_reserves as f64;
+                let exponent = aiw3_weight as f64 / ai_agent_weight as f64;
+                let expected = reserve_out
+                    * (1.0 - (reserve_in / (reserve_in + amount_in as f64)).powf(exponent));
+
+                assert!(
+                    amount_out as f64 <= expected,
+                    "{aiw3_weight}/{ai_agent_weight} in {amount_in}: {amount_out} > {expected}"
+                );
+                assert!(expected - (amount_out as f64) <= reserve_out * 1e-9 + 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn weighted_output_rejects_inputs_above_the_in_ratio() {
+        let pool = weighted_pool(5000, 5000);
+
+        assert!(pool.calculate_weighted_output(500_000_000_000, false).is_ok());
+        assert!(pool.calculate_weighted_output(500_000_000_001, true).is_err());
+    }
+
+    #[test]
+    fn liquidity_deposits_round_up_and_withdrawals_round_down() {
+        let mut pool = weighted_pool(8000, 2000);
+        pool.total_shares = 3_000_000_000_000;
+
+        // 1/3 of the pool, reserves are not a multiple of three
+        assert_eq!(pool.liquidity_for_shares(1_000_000_000_000, true).unwrap(), (333_333_333_334, 1_666_666_666_667));
+        assert_eq!(pool.liquidity_for_shares(1_000_000_000_000, false).unwrap(), (333_333_333_333, 1_666_666_666_666));
+        assert!(pool.liquidity_for_shares(0, true).is_err());
+
+        pool.total_shares = 0;
+        assert!(pool.liquidity_for_shares(1, true).is_err());
+    }
+
+    #[test]
+    fn liquidity_changes_keep_the_price() {
+        let mut pool = weighted_pool(5000, 5000);
+        pool.total_shares = pool.aiw3_reserves;
+        let price = |pool: &SwapPool| pool.ai_agent_reserves as f64 / pool.aiw3_reserves as f64;
+        let initial_price = price(&pool);
+
+        assert_eq!(pool.add_liquidity(500_000_000_000).unwrap(), (500_000_000_000, 2_500_000_000_000));
+        assert_eq!(pool.total_shares, 1_500_000_000_000);
+        assert_eq!(price(&pool), initial_price);
+
+        assert_eq!(pool.remove_liquidity(1_000_000_000_000).unwrap(), (1_000_000_000_000, 5_000_000_000_000));
+        assert_eq!(pool.total_shares, 500_000_000_000);
+        assert_eq!(price(&pool), initial_price);
+    }
+
+    #[test]
+    fn liquidity_removal_keeps_minimum_reserves_until_the_last_share() {
+        let mut pool = weighted_pool(5000, 5000);
+        pool.total_shares = pool.aiw3_reserves;
+
+        assert!(pool.clone().remove_liquidity(pool.total_shares + 1).is_err());
+        assert!(pool.clone().remove_liquidity(pool.total_shares - 1).is_err());
+
+        assert_eq!(pool.remove_liquidity(pool.total_shares).unwrap(), (1_000_000_000_000, 5_000_000_000_000));
+        assert_eq!((pool.aiw3_reserves, pool.ai_agent_reserves, pool.total_shares), (0, 0, 0));
+    }
+
+    #[test]
+    fn versioned_layouts_keep_the_account_size() {
+        // Size of the current layout, later fields are carved out of the padding
+        assert_eq!(SwapPool::SIZE, 226);
+        assert_eq!(SwapPool::V1_SIZE, 146);
+    }
+
+    #[test]
+    fn legacy_pools_migrate_to_the_current_layout() {
+        let pool = SwapPool::test_pool();
+
+        assert_eq!(pool.version, crate::SWAP_POOL_VERSION);
+        assert!(pool.curve_type == CurveType::Oracle);
+        assert_eq!(pool.total_shares, 0);
+        assert_eq!(pool.try_to_vec().unwrap().len(), SwapPool::SIZE - SwapPool::DISCRIMINATOR_SIZE);
+    }
+
+    #[test]
+    fn launch_window_is_disabled_without_protection_slots() {
+        let mut pool = launch_pool();
+        pool.launch_protection_slots = 0;
+
+        assert!(!pool.in_launch_window(LAUNCH_SLOT));
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT), 30);
+    }
+
+    #[test]
+    fn launch_window_covers_slots_until_it_is_opened() {
+        let mut pool = launch_pool();
+        pool.launch_slot = 0;
+
+        assert!(pool.in_launch_window(u64::MAX));
+        assert_eq!(pool.swap_fee_basis_points_at(u64::MAX), 1_030);
+    }
+
+    #[test]
+    fn launch_window_ends_after_protection_slots() {
+        let pool = launch_pool();
+
+        assert!(pool.in_launch_window(LAUNCH_SLOT));
+        assert!(pool.in_launch_window(LAUNCH_SLOT + WINDOW - 1));
+        assert!(!pool.in_launch_window(LAUNCH_SLOT + WINDOW));
+    }
+
+    #[test]
+    fn launch_fee_decays_linearly_to_swap_fee() {
+        let pool = launch_pool();
+
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT), 1_030);
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT + WINDOW / 2), 530);
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT + WINDOW - 1), 40);
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT + WINDOW), 30);
+    }
+
+    #[test]
+    fn launch_fee_below_swap_fee_is_ignored() {
+        let mut pool = launch_pool();
+        pool.launch_fee_basis_points = 10;
+
+        assert_eq!(pool.swap_fee_basis_points_at(LAUNCH_SLOT), 30);
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TransferHookApproval {
+    pub program_id: Pubkey,           // Transfer hook program approved by the admin
+    pub approved_by: Pubkey,
+    pub approved_timestamp: i64,
+    pub bump: u8,
+}
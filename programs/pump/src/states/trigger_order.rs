@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TriggerOrder {
+    pub owner: Pubkey,
+    pub swap_pool: Pubkey,
+    pub order_id: u64,                // Owner-chosen id, part of the order seeds
+    pub kind: TriggerOrderKind,
+
+    pub amount_in: u64,               // AI Agent tokens escrowed (net of transfer fees)
+    pub trigger_price: i64,           // AW3 price from the Pyth feed, in feed units
+    pub min_amount_out: u64,          // Minimum AIW3 the owner accepts on execution
+    pub max_price_age: u64,           // Oldest oracle price accepted at execution, in seconds
+    pub created_timestamp: i64,
+
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum TriggerOrderKind {
+    StopLoss,   // Executes once the price falls to the trigger
+    TakeProfit, // Executes once the price rises to the trigger
+}
+
+impl TriggerOrder {
+    pub fn is_triggered(&self, price: i64) -> bool {
+        match self.kind {
+            TriggerOrderKind::StopLoss => price <= self.trigger_price,
+            TriggerOrderKind::TakeProfit => price >= self.trigger_price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(kind: TriggerOrderKind) -> TriggerOrder {
+        TriggerOrder {
+            owner: Pubkey::default(),
+            swap_pool: Pubkey::default(),
+            order_id: 0,
+            kind,
+            amount_in: 1_000,
+            trigger_price: 50_000,
+            min_amount_out: 0,
+            max_price_age: crate::MAXIMUM_AGE,
+            created_timestamp: 0,
+            bump: 255,
+            vault_bump: 255,
+        }
+    }
+
+    #[test]
+    fn stop_loss_triggers_at_or_below_the_price() {
+        let order = order(TriggerOrderKind::StopLoss);
+
+        assert!(!order.is_triggered(50_001));
+        assert!(order.is_triggered(50_000));
+        assert!(order.is_triggered(49_999));
+    }
+
+    #[test]
+    fn take_profit_triggers_at_or_above_the_price() {
+        let order = order(TriggerOrderKind::TakeProfit);
+
+        assert!(!order.is_triggered(49_999));
+        assert!(order.is_triggered(50_000));
+        assert!(order.is_triggered(50_001));
+    }
+}
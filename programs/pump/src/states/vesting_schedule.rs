@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub beneficiary: Pubkey,
+    pub schedule_id: u64,             // Grant number for this beneficiary and mint
+    pub mint: Pubkey,
+    pub funder: Pubkey,               // Admin that escrowed the tokens
+
+    pub total_amount: u64,            // Tokens held for the beneficiary (net of transfer fees)
+    pub claimed_amount: u64,          // Tokens already released
+
+    pub start_timestamp: i64,         // Vesting start
+    pub cliff_timestamp: i64,         // Nothing vests before this time
+    pub end_timestamp: i64,           // Everything is vested from this time
+
+    pub revocable: bool,
+    pub revoked: bool,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl VestingSchedule {
+    /// Amount vested at `now`, releasing linearly from the cliff to the end
+    pub fn vested_amount(&self, now: i64) -> std::result::Result<u64, crate::PumpError> {
+        if now < self.cliff_timestamp {
+            return Ok(0);
+        }
+
+        if now >= self.end_timestamp {
+            return Ok(self.total_amount);
+        }
+
+        let elapsed = (now - self.cliff_timestamp) as u128;
+        let duration = (self.end_timestamp - self.cliff_timestamp) as u128;
+
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .ok_or(crate::PumpError::MathOverflow)?
+            .checked_div(duration)
+            .ok_or(crate::PumpError::MathOverflow)?;
+
+        Ok(vested as u64)
+    }
+
+    pub fn claimable_amount(&self, now: i64) -> std::result::Result<u64, crate::PumpError> {
+        self.vested_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(crate::PumpError::MathOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000;
+
+    fn schedule() -> VestingSchedule {
+        VestingSchedule {
+            beneficiary: Pubkey::default(),
+            schedule_id: 0,
+            mint: Pubkey::default(),
+            funder: Pubkey::default(),
+            total_amount: 2_400,
+            claimed_amount: 0,
+            start_timestamp: START,
+            cliff_timestamp: START + crate::LOCK_PERIOD as i64,
+            end_timestamp: START + crate::FULL_UNLOCK_PERIOD as i64,
+            revocable: true,
+            revoked: false,
+            bump: 255,
+            vault_bump: 255,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let schedule = schedule();
+
+        assert_eq!(schedule.vested_amount(START).unwrap(), 0);
+        assert_eq!(schedule.vested_amount(schedule.cliff_timestamp - 1).unwrap(), 0);
+        assert_eq!(schedule.vested_amount(schedule.cliff_timestamp).unwrap(), 0);
+    }
+
+    #[test]
+    fn tokens_vest_linearly_after_the_cliff() {
+        let schedule = schedule();
+        let month = crate::SECONDS_PER_MONTH as i64;
+
+        assert_eq!(schedule.vested_amount(schedule.cliff_timestamp + month).unwrap(), 100);
+        assert_eq!(schedule.vested_amount(schedule.cliff_timestamp + 12 * month).unwrap(), 1_200);
+        assert_eq!(schedule.vested_amount(schedule.end_timestamp - 1).unwrap(), 2_399);
+        assert_eq!(schedule.vested_amount(schedule.end_timestamp).unwrap(), 2_400);
+        assert_eq!(schedule.vested_amount(i64::MAX).unwrap(), 2_400);
+    }
+
+    #[test]
+    fn claimable_amount_excludes_claimed_tokens() {
+        let mut schedule = schedule();
+        schedule.claimed_amount = 1_000;
+
+        assert_eq!(schedule.claimable_amount(schedule.end_timestamp).unwrap(), 1_400);
+        assert!(schedule.claimable_amount(START).is_err());
+    }
+}
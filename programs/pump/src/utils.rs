@@ -1,17 +1,34 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use solana_program::program::invoke_signed;
-use hex;
+use anchor_lang::system_program;
+use anchor_spl::{
+    token_interface::{
+        sync_native, transfer_checked, Mint as MintAccount, SyncNative, TokenInterface,
+        TransferChecked,
+    },
+};
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use solana_program::program::{invoke, invoke_signed};
 use spl_token_2022::{
     extension::{
-        transfer_fee::{instruction::transfer_checked_with_fee, TransferFeeConfig},
+        confidential_transfer::ConfidentialTransferMint,
+        confidential_transfer_fee::ConfidentialTransferFeeConfig,
+        default_account_state::DefaultAccountState,
+        non_transferable::NonTransferable,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{
+            instruction::{harvest_withheld_tokens_to_mint, transfer_checked_with_fee},
+            TransferFeeAmount, TransferFeeConfig,
+        },
+        transfer_hook,
         BaseStateWithExtensions,
         StateWithExtensions,
     },
-    state::Mint,
+    onchain::invoke_transfer_checked,
+    state::{AccountState, Mint},
 };
 
 /// Transfer tokens with fee using Token 2022
+#[allow(clippy::too_many_arguments)]
 pub fn invoke_transfer_checked_with_fee<'a>(
     token_program_id: &Pubkey,
     source_info: AccountInfo<'a>,
@@ -52,7 +69,147 @@ pub fn get_transfer_fee_config(mint_info: &AccountInfo) -> Result<Option<Transfe
     let mint_data = mint_info.try_borrow_data()?;
     let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
     
-    Ok(mint.get_extension::<TransferFeeConfig>().ok().map(|config| *config))
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Transfer fee in basis points a mint charges in `epoch`, 0 for SPL Token
+/// mints and Token-2022 mints without the transfer fee extension
+pub fn get_transfer_fee_basis_points(mint_info: &AccountInfo, epoch: u64) -> Result<u16> {
+    if *mint_info.owner != anchor_spl::token_2022::ID {
+        return Ok(0);
+    }
+
+    Ok(get_transfer_fee_config(mint_info)?
+        .map_or(0, |config| u16::from(config.get_epoch_fee(epoch).transfer_fee_basis_points)))
+}
+
+/// Get the transfer hook program of a mint, `None` for SPL Token mints and
+/// Token-2022 mints without an active hook
+pub fn get_transfer_hook_program_id(mint_info: &AccountInfo) -> Result<Option<Pubkey>> {
+    if *mint_info.owner != anchor_spl::token_2022::ID {
+        return Ok(None);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
+/// Sweep the transfer fees withheld in a Token-2022 account into its mint so
+/// the account can be closed, a no-op when nothing is withheld
+pub fn harvest_withheld_fees<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint_info: AccountInfo<'info>,
+    account_info: AccountInfo<'info>,
+) -> Result<()> {
+    if token_program.key() != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+
+    let withheld_amount = {
+        let account_data = account_info.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)?;
+        account
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |fee_amount| u64::from(fee_amount.withheld_amount))
+    };
+    if withheld_amount == 0 {
+        return Ok(());
+    }
+
+    let harvest_ix = harvest_withheld_tokens_to_mint(
+        &token_program.key(),
+        mint_info.key,
+        &[account_info.key],
+    )?;
+
+    invoke(&harvest_ix, &[mint_info, account_info])?;
+
+    Ok(())
+}
+
+/// Reject mints whose authorities or extensions could freeze, seize or lock
+/// tokens held by a pool vault
+///
+/// A freeze authority is rejected on SPL Token mints too, since it can freeze
+/// the vault just the same; pools of trusted freezable mints (e.g. USDC) are
+/// created with the admin override instead.
+pub fn check_mint_extensions(mint_info: &AccountInfo) -> Result<()> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+    require!(
+        mint.base.freeze_authority.is_none(),
+        crate::PumpError::MintHasFreezeAuthority
+    );
+
+    if let Ok(permanent_delegate) = mint.get_extension::<PermanentDelegate>() {
+        require!(
+            Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+            crate::PumpError::MintHasPermanentDelegate
+        );
+    }
+
+    require!(
+        mint.get_extension::<NonTransferable>().is_err(),
+        crate::PumpError::MintNonTransferable
+    );
+
+    // Pool reserves are tracked from public balances only, which stay usable
+    // unless holders can move to confidential balances without the authority
+    // or fees are withheld confidentially
+    if let Ok(confidential_transfer) = mint.get_extension::<ConfidentialTransferMint>() {
+        require!(
+            !bool::from(confidential_transfer.auto_approve_new_accounts)
+                && mint.get_extension::<ConfidentialTransferFeeConfig>().is_err(),
+            crate::PumpError::MintConfidentialTransfer
+        );
+    }
+
+    if let Ok(default_account_state) = mint.get_extension::<DefaultAccountState>() {
+        require!(
+            default_account_state.state != AccountState::Frozen as u8,
+            crate::PumpError::MintDefaultAccountStateFrozen
+        );
+    }
+
+    Ok(())
+}
+
+/// Reject mints whose transfer hook program has not been approved by the
+/// admin, for pools created with `restrict_transfer_hooks`
+pub fn check_transfer_hook(
+    mint_info: &AccountInfo,
+    restrict_transfer_hooks: bool,
+    approval: &Option<Box<Account<crate::TransferHookApproval>>>,
+) -> Result<()> {
+    if !restrict_transfer_hooks {
+        return Ok(());
+    }
+
+    if let Some(program_id) = get_transfer_hook_program_id(mint_info)? {
+        require!(
+            approval
+                .as_ref()
+                .is_some_and(|approval| approval.program_id == program_id),
+            crate::PumpError::TransferHookNotApproved
+        );
+    }
+
+    Ok(())
+}
+
+/// Amount of a `DECIMALS` token expressed in base units of a mint with
+/// `decimals`, saturating at `u64::MAX`
+pub fn scale_to_decimals(amount: u64, decimals: u8) -> u64 {
+    if decimals >= crate::DECIMALS {
+        10u64
+            .checked_pow((decimals - crate::DECIMALS) as u32)
+            .map_or(u64::MAX, |factor| amount.saturating_mul(factor))
+    } else {
+        amount / 10u64.pow((crate::DECIMALS - decimals) as u32)
+    }
 }
 
 /// Calculate transfer fee for a given amount
@@ -78,10 +235,7 @@ pub fn get_validated_price(
     max_age: u64,
 ) -> Result<i64> {
     // Feed IDs are provided as hex strings in the configuration
-    let feed_id_vec = hex::decode(feed_id)
-        .map_err(|_| error!(crate::PumpError::InvalidPriceOracle))?;
-    let feed_id_bytes: [u8; 32] = feed_id_vec
-        .try_into()
+    let feed_id_bytes = get_feed_id_from_hex(feed_id)
         .map_err(|_| error!(crate::PumpError::InvalidPriceOracle))?;
     
     let price_feed = price_oracle.get_price_no_older_than(
@@ -92,3 +246,499 @@ pub fn get_validated_price(
 
     Ok(price_feed.price)
 }
+
+/// Fetch the AW3 price from Pyth, no older than `max_age` seconds, and
+/// enforce the expected price range
+pub fn get_aw3_price(price_oracle: &Account<PriceUpdateV2>, max_age: u64) -> Result<i64> {
+    // Use the validated price function with proper feed ID verification
+    let price = get_validated_price(price_oracle, crate::AW3_FEED_ID, max_age)?;
+    
+    // Additional validation: ensure price is positive and reasonable
+    require!(
+        price > 0,
+        crate::PumpError::InvalidPriceOracle
+    );
+    
+    // Add bounds check for price (adjust these based on expected AW3 price range)
+    require!(
+        (1_000..=1_000_000_000).contains(&price), // $0.01 to $10,000 range
+        crate::PumpError::InvalidPriceOracle
+    );
+    
+    Ok(price)
+}
+
+/// AW3 price used to price a swap on `swap_pool`. Oracle pools need the
+/// feed, weighted pools are priced by their reserves and report 0.
+pub fn get_pool_aw3_price(
+    swap_pool: &crate::SwapPool,
+    price_oracle: Option<&Account<PriceUpdateV2>>,
+    max_age: u64,
+) -> Result<i64> {
+    match swap_pool.curve_type {
+        crate::CurveType::WeightedProduct => Ok(0),
+        crate::CurveType::Oracle => get_aw3_price(
+            price_oracle.ok_or(crate::PumpError::InvalidPriceOracle)?,
+            max_age,
+        ),
+    }
+}
+
+/// Whether the (already seed-checked) role account grants `role`
+pub fn has_role(role_account: &Option<Box<Account<crate::RoleAccount>>>, role: u8) -> bool {
+    role_account
+        .as_ref()
+        .is_some_and(|role_account| role_account.has_role(role))
+}
+
+/// Bytes the TokenMetadata extension adds to a mint: the TLV header, update
+/// authority, mint, the three length-prefixed strings and an empty
+/// additional metadata list
+pub fn token_metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    4 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
+
+/// Transfer tokens through the mint's own program: `transfer_checked` for
+/// SPL Token mints, `transfer_checked_with_fee` for Token-2022 mints.
+/// Mints with a transfer hook resolve the hook's `ExtraAccountMetaList`
+/// from `additional_accounts`, which must hold the validation account, the
+/// hook program and every extra account the hook requires.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, MintAccount>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+    additional_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    require_keys_eq!(
+        *mint.to_account_info().owner,
+        token_program.key(),
+        crate::PumpError::InvalidTokenMint
+    );
+
+    if get_transfer_hook_program_id(&mint.to_account_info())?.is_some() {
+        // Token-2022 withholds any transfer fee on its own here
+        invoke_transfer_checked(
+            &token_program.key(),
+            from,
+            mint.to_account_info(),
+            to,
+            authority,
+            additional_accounts,
+            amount,
+            mint.decimals,
+            signer_seeds,
+        )?;
+
+        Ok(())
+    } else if token_program.key() == anchor_spl::token_2022::ID {
+        let fee = calculate_transfer_fee(&mint.to_account_info(), amount)?;
+
+        invoke_transfer_checked_with_fee(
+            &token_program.key(),
+            from,
+            mint.to_account_info(),
+            to,
+            authority,
+            amount,
+            mint.decimals,
+            fee,
+            signer_seeds,
+        )
+    } else {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from,
+                    mint: mint.to_account_info(),
+                    to,
+                    authority,
+                },
+                signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+}
+
+/// Create a program-owned PDA of `space` bytes paid by `payer`. Like
+/// Anchor's `init`, this also works when the address was pre-funded.
+pub fn create_pda_account<'info>(
+    system_program: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount {
+                    from: payer,
+                    to: account,
+                },
+                signer_seeds,
+            ),
+            rent_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if current_lamports < rent_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            rent_lamports - current_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign {
+                account_to_assign: account,
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Whether `mint` is the wrapped SOL mint of SPL Token or Token-2022
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == anchor_spl::token::spl_token::native_mint::ID || *mint == spl_token_2022::native_mint::ID
+}
+
+/// Wrap native lamports from `owner` into its wrapped SOL token account
+pub fn wrap_native_sol<'info>(
+    system_program: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    owner: AccountInfo<'info>,
+    native_account: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: owner,
+                to: native_account.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    sync_native(CpiContext::new(
+        token_program.to_account_info(),
+        SyncNative {
+            account: native_account,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::borsh1::get_instance_packed_len;
+    use solana_program::program_option::COption;
+    use spl_token_2022::extension::{
+        transfer_fee::TransferFee, transfer_hook::TransferHook, BaseStateWithExtensionsMut,
+        ExtensionType, StateWithExtensionsMut,
+    };
+    use spl_token_metadata_interface::state::TokenMetadata;
+
+    /// Initialized mint owned by `owner` with `extensions`, which `init`
+    /// sets up. Leaked so it can back an `AccountInfo`.
+    fn mint_info(
+        owner: &'static Pubkey,
+        extensions: &[ExtensionType],
+        init: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+    ) -> AccountInfo<'static> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+        let data = vec![0u8; len].leak();
+
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(data).unwrap();
+        mint.base.decimals = 6;
+        mint.base.is_initialized = true;
+        init(&mut mint);
+        mint.pack_base();
+        if !extensions.is_empty() {
+            mint.init_account_type().unwrap();
+        }
+
+        AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            data,
+            owner,
+            false,
+            0,
+        )
+    }
+
+    /// Mint whose transfer hook runs `program_id`
+    fn hooked_mint_info(program_id: Pubkey) -> AccountInfo<'static> {
+        mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::TransferHook],
+            |mint| {
+                let hook = mint.init_extension::<TransferHook>(true).unwrap();
+                hook.program_id = Some(program_id).try_into().unwrap();
+            },
+        )
+    }
+
+    /// Admin approval of `program_id`, leaked so it can back an `Account`
+    fn approval(program_id: Pubkey) -> Option<Box<Account<'static, crate::TransferHookApproval>>> {
+        let mut data = Vec::new();
+        crate::TransferHookApproval {
+            program_id,
+            approved_by: Pubkey::default(),
+            approved_timestamp: 0,
+            bump: 255,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+
+        let info = Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            data.leak(),
+            &crate::ID,
+            false,
+            0,
+        )));
+
+        Some(Box::new(Account::try_from(info).unwrap()))
+    }
+
+    fn transfer_fee(epoch: u64, transfer_fee_basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: u64::MAX.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    #[test]
+    fn amounts_scale_with_the_mint_decimals() {
+        assert_eq!(scale_to_decimals(1_000_000, 6), 1_000_000);
+        assert_eq!(scale_to_decimals(1_000_000, 9), 1_000_000_000);
+        assert_eq!(scale_to_decimals(1_000_000, 0), 1);
+        assert_eq!(scale_to_decimals(crate::VESTING_AMOUNT, 9), crate::VESTING_AMOUNT * 1_000);
+        assert_eq!(scale_to_decimals(crate::VESTING_AMOUNT, 30), u64::MAX);
+    }
+
+    #[test]
+    fn token_metadata_space_matches_the_packed_extension() {
+        let metadata = TokenMetadata {
+            name: "AI Agent".to_string(),
+            symbol: "AGENT".to_string(),
+            uri: "https://example.com/agent.json".to_string(),
+            ..TokenMetadata::default()
+        };
+
+        assert_eq!(
+            token_metadata_space(&metadata.name, &metadata.symbol, &metadata.uri),
+            4 + get_instance_packed_len(&metadata).unwrap()
+        );
+    }
+
+    #[test]
+    fn transfer_fee_follows_the_scheduled_epoch() {
+        let mint = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::TransferFeeConfig],
+            |mint| {
+                let config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+                config.older_transfer_fee = transfer_fee(0, 100);
+                config.newer_transfer_fee = transfer_fee(10, 250);
+            },
+        );
+
+        assert_eq!(get_transfer_fee_basis_points(&mint, 9).unwrap(), 100);
+        assert_eq!(get_transfer_fee_basis_points(&mint, 10).unwrap(), 250);
+    }
+
+    #[test]
+    fn mints_without_a_transfer_fee_charge_nothing() {
+        let mint = mint_info(&spl_token_2022::ID, &[], |_| {});
+
+        assert_eq!(get_transfer_fee_basis_points(&mint, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn legacy_spl_token_mints_have_no_fee_or_hook() {
+        let mint = mint_info(&spl_token::ID, &[], |_| {});
+
+        assert_eq!(get_transfer_fee_basis_points(&mint, 0).unwrap(), 0);
+        assert_eq!(get_transfer_hook_program_id(&mint).unwrap(), None);
+        assert!(check_mint_extensions(&mint).is_ok());
+    }
+
+    #[test]
+    fn transfer_hook_program_is_read_from_the_mint() {
+        let program_id = Pubkey::new_unique();
+
+        assert_eq!(
+            get_transfer_hook_program_id(&hooked_mint_info(program_id)).unwrap(),
+            Some(program_id)
+        );
+    }
+
+    #[test]
+    fn restricted_pools_require_an_approved_hook() {
+        let program_id = Pubkey::new_unique();
+        let mint = hooked_mint_info(program_id);
+
+        assert!(check_transfer_hook(&mint, false, &None).is_ok());
+        assert!(check_transfer_hook(&mint, true, &None).is_err());
+        assert!(check_transfer_hook(&mint, true, &approval(Pubkey::new_unique())).is_err());
+        assert!(check_transfer_hook(&mint, true, &approval(program_id)).is_ok());
+    }
+
+    #[test]
+    fn mints_without_a_hook_need_no_approval() {
+        let mint = mint_info(&spl_token_2022::ID, &[], |_| {});
+
+        assert!(check_transfer_hook(&mint, true, &None).is_ok());
+    }
+
+    #[test]
+    fn hazardous_mints_are_rejected() {
+        // Freeze authorities can lock vaults on either token program
+        let freezable = mint_info(&spl_token::ID, &[], |mint| {
+            mint.base.freeze_authority = COption::Some(Pubkey::new_unique());
+        });
+        assert!(check_mint_extensions(&freezable).is_err());
+
+        let delegated = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::PermanentDelegate],
+            |mint| {
+                let delegate = mint.init_extension::<PermanentDelegate>(true).unwrap();
+                delegate.delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+            },
+        );
+        assert!(check_mint_extensions(&delegated).is_err());
+
+        let non_transferable = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::NonTransferable],
+            |mint| {
+                mint.init_extension::<NonTransferable>(true).unwrap();
+            },
+        );
+        assert!(check_mint_extensions(&non_transferable).is_err());
+
+        let auto_approved_confidential = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::ConfidentialTransferMint],
+            |mint| {
+                let confidential = mint.init_extension::<ConfidentialTransferMint>(true).unwrap();
+                confidential.auto_approve_new_accounts = true.into();
+            },
+        );
+        assert!(check_mint_extensions(&auto_approved_confidential).is_err());
+
+        let confidential_fees = mint_info(
+            &spl_token_2022::ID,
+            &[
+                ExtensionType::ConfidentialTransferMint,
+                ExtensionType::ConfidentialTransferFeeConfig,
+            ],
+            |mint| {
+                mint.init_extension::<ConfidentialTransferMint>(true).unwrap();
+                mint.init_extension::<ConfidentialTransferFeeConfig>(true).unwrap();
+            },
+        );
+        assert!(check_mint_extensions(&confidential_fees).is_err());
+
+        let frozen_by_default = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::DefaultAccountState],
+            |mint| {
+                let state = mint.init_extension::<DefaultAccountState>(true).unwrap();
+                state.state = AccountState::Frozen as u8;
+            },
+        );
+        assert!(check_mint_extensions(&frozen_by_default).is_err());
+    }
+
+    #[test]
+    fn harmless_extensions_are_accepted() {
+        let revoked_delegate = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::PermanentDelegate],
+            |mint| {
+                mint.init_extension::<PermanentDelegate>(true).unwrap();
+            },
+        );
+        assert!(check_mint_extensions(&revoked_delegate).is_ok());
+
+        let initialized_by_default = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::DefaultAccountState],
+            |mint| {
+                let state = mint.init_extension::<DefaultAccountState>(true).unwrap();
+                state.state = AccountState::Initialized as u8;
+            },
+        );
+        assert!(check_mint_extensions(&initialized_by_default).is_ok());
+
+        // Accounts need the authority's approval before holding confidential balances
+        let approved_confidential = mint_info(
+            &spl_token_2022::ID,
+            &[ExtensionType::ConfidentialTransferMint],
+            |mint| {
+                mint.init_extension::<ConfidentialTransferMint>(true).unwrap();
+            },
+        );
+        assert!(check_mint_extensions(&approved_confidential).is_ok());
+    }
+
+    #[test]
+    fn aw3_feed_id_resolves_to_a_pyth_feed_id() {
+        let feed_id = get_feed_id_from_hex(crate::AW3_FEED_ID).unwrap();
+        assert_eq!(feed_id[0], 0xfe);
+        assert_eq!(feed_id[31], 0xcd);
+    }
+
+    #[test]
+    fn native_mints_of_both_token_programs_are_recognized() {
+        assert!(is_native_mint(&spl_token::native_mint::ID));
+        assert!(is_native_mint(&spl_token_2022::native_mint::ID));
+        assert!(!is_native_mint(&Pubkey::new_unique()));
+    }
+}
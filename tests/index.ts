@@ -45,6 +45,20 @@ describe("Bonding Curve Test Suite", function() {
   require("./unit/initialize.test");
   require("./unit/create-token.test");
   require("./unit/swap.test");
+  require("./unit/native-sol.test");
+  require("./unit/weighted-pool.test");
+  require("./unit/liquidity.test");
+  require("./unit/pool-status.test");
+  require("./unit/pause.test");
+  require("./unit/close-pool.test");
+  require("./unit/update-pool.test");
+  require("./unit/ownership.test");
+  require("./unit/roles.test");
+  require("./unit/timelock.test");
+  require("./unit/burn.test");
+  require("./unit/transfer-fees.test");
+  require("./unit/launch-protection.test");
+  require("./unit/trigger-orders.test");
   require("./integration/full-workflow.test");
 });
 
@@ -0,0 +1,77 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getMint, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, INITIAL_SUPPLY } from "../utils/test-setup";
+
+// Most the protocol burns per mint, for a 6-decimal token
+const BURN_AMOUNT = new BN("5825082690385");
+
+describe("Burn Treasury", () => {
+  let ctx: TestContext;
+  let mint: PublicKey;
+  let treasury: PublicKey;
+  let burnRecord: PublicKey;
+
+  const burnTreasury = (admin: Keypair, amount: BN) =>
+    ctx.program.methods
+      .burnTreasury({ amount })
+      .accounts({
+        admin: admin.publicKey,
+        config: ctx.config,
+        mint,
+        treasuryTokenAccount: treasury,
+        burnRecord,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+
+    // The treasury is the config's associated token account
+    mint = await TestSetup.createFundedMint(ctx, ctx.admin.publicKey, INITIAL_SUPPLY);
+    treasury = await TestSetup.mintTokens(ctx, mint, ctx.config, BURN_AMOUNT.muln(2));
+    burnRecord = PublicKey.findProgramAddressSync(
+      [Buffer.from("burn_record"), mint.toBuffer()],
+      ctx.program.programId
+    )[0];
+  });
+
+  describe("Success Cases", () => {
+    it("Should burn from the treasury and track the running total", async () => {
+      const amount = BURN_AMOUNT.divn(10);
+
+      await burnTreasury(ctx.admin, amount);
+      await burnTreasury(ctx.admin, amount);
+
+      const record = await ctx.program.account.burnRecord.fetch(burnRecord);
+      TestSetup.expectBNEqual(record.totalBurned, amount.muln(2));
+
+      const balance = await TestSetup.getTokenBalance(ctx.connection, treasury);
+      TestSetup.expectBNEqual(balance, BURN_AMOUNT.muln(2).sub(amount.muln(2)));
+
+      const mintAccount = await getMint(ctx.connection, mint);
+      expect(mintAccount.supply.toString()).to.equal(
+        INITIAL_SUPPLY.add(BURN_AMOUNT.muln(2)).sub(amount.muln(2)).toString()
+      );
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject burns from anyone but the admin", async () => {
+      await TestSetup.expectError(() => burnTreasury(ctx.user1, new BN(1)), "NotAdmin");
+    });
+
+    it("Should reject burning more than the treasury holds", async () => {
+      await TestSetup.expectError(() => burnTreasury(ctx.admin, BURN_AMOUNT.muln(3)), "InsufficientBalance");
+      await TestSetup.expectError(() => burnTreasury(ctx.admin, new BN(0)), "AmountTooSmall");
+    });
+
+    it("Should stop burning at the committed burn amount", async () => {
+      await TestSetup.expectError(() => burnTreasury(ctx.admin, BURN_AMOUNT), "BurnLimitExceeded");
+    });
+  });
+});
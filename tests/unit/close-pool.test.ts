@@ -0,0 +1,118 @@
+import { Keypair } from "@solana/web3.js";
+import { getMint, getTransferFeeConfig } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  TestSetup,
+  TestContext,
+  TestPool,
+  INITIAL_LIQUIDITY,
+  TOKEN_2022_PROGRAM_ID,
+} from "../utils/test-setup";
+
+describe("Close Pool", () => {
+  let ctx: TestContext;
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+  });
+
+  const closePool = async (pool: TestPool, authority: Keypair) =>
+    ctx.program.methods
+      .closePool()
+      .accounts({
+        authority: authority.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+        aiw3TokenMint: pool.aiw3Mint,
+        aiAgentTokenMint: pool.aiAgentMint,
+        swapPool: pool.swapPool,
+        creator: pool.creator.publicKey,
+        creatorLiquidityPosition: pool.creatorLiquidityPosition,
+        poolAiw3Vault: pool.poolAiw3Vault,
+        poolAiAgentVault: pool.poolAiAgentVault,
+        destinationAiw3Account: await TestSetup.tokenAccount(ctx, pool.aiw3Mint, authority.publicKey),
+        destinationAiAgentAccount: await TestSetup.tokenAccount(
+          ctx, pool.aiAgentMint, authority.publicKey, pool.aiAgentTokenProgram
+        ),
+        aiw3TokenProgram: pool.aiw3TokenProgram,
+        aiAgentTokenProgram: pool.aiAgentTokenProgram,
+      })
+      .signers([authority])
+      .rpc();
+
+  describe("Success Cases", () => {
+    it("Should drain the vaults and return the rent to the creator", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+      await TestSetup.setPoolStatus(ctx, pool, false);
+
+      const destination = await TestSetup.tokenAccount(ctx, pool.aiw3Mint, ctx.admin.publicKey);
+      const destinationBefore = await TestSetup.getTokenBalance(ctx.connection, destination);
+      const creatorLamportsBefore = await ctx.connection.getBalance(ctx.user1.publicKey);
+
+      await closePool(pool, ctx.admin);
+
+      const destinationAfter = await TestSetup.getTokenBalance(ctx.connection, destination);
+      TestSetup.expectBNEqual(destinationAfter.sub(destinationBefore), INITIAL_LIQUIDITY);
+
+      expect(await ctx.connection.getAccountInfo(pool.swapPool)).to.be.null;
+      expect(await ctx.connection.getAccountInfo(pool.poolAiw3Vault)).to.be.null;
+      expect(await ctx.connection.getAccountInfo(pool.poolAiAgentVault)).to.be.null;
+      expect(await ctx.connection.getAccountInfo(pool.creatorLiquidityPosition)).to.be.null;
+
+      const creatorLamportsAfter = await ctx.connection.getBalance(ctx.user1.publicKey);
+      expect(creatorLamportsAfter).to.be.greaterThan(creatorLamportsBefore);
+    });
+
+    it("Should move fees withheld in the vaults to the mint before closing them", async () => {
+      const token = await TestSetup.createToken(ctx, ctx.user1);
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1, { aiAgentToken: token });
+      await TestSetup.setPoolStatus(ctx, pool, false);
+
+      await closePool(pool, ctx.admin);
+
+      expect(await ctx.connection.getAccountInfo(pool.poolAiAgentVault)).to.be.null;
+
+      const mint = await getMint(ctx.connection, token.mint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(getTransferFeeConfig(mint)!.withheldAmount > BigInt(0)).to.be.true;
+    });
+
+    it("Should let the pool creator close their own pool", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+      await TestSetup.setPoolStatus(ctx, pool, false);
+
+      await closePool(pool, ctx.user1);
+
+      expect(await ctx.connection.getAccountInfo(pool.swapPool)).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should refuse to close an active pool", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+
+      await TestSetup.expectError(() => closePool(pool, ctx.admin), "InvalidPoolState");
+    });
+
+    it("Should refuse to close a pool for anyone but the admin or creator", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+      await TestSetup.setPoolStatus(ctx, pool, false);
+
+      await TestSetup.expectError(() => closePool(pool, ctx.user2), "InvalidOwner");
+    });
+
+    it("Should refuse to close a pool while providers hold shares", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+      const shares = INITIAL_LIQUIDITY.divn(10);
+      await TestSetup.mintTokens(ctx, pool.aiw3Mint, ctx.user2.publicKey, shares);
+      await TestSetup.mintTokens(ctx, pool.aiAgentMint, ctx.user2.publicKey, shares);
+      await TestSetup.addLiquidity(ctx, pool, ctx.user2, {
+        shares,
+        maxAiw3Amount: shares,
+        maxAiAgentAmount: shares,
+      });
+      await TestSetup.setPoolStatus(ctx, pool, false);
+
+      await TestSetup.expectError(() => closePool(pool, ctx.admin), "LiquidityOutstanding");
+    });
+  });
+});
@@ -1,443 +1,176 @@
-import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { Pump } from "../../target/types/pump";
-import { PublicKey, Keypair } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import { getMint, getTransferFeeConfig } from "@solana/spl-token";
 import { expect } from "chai";
 import BN from "bn.js";
-import { TestSetup, TestContext, TOKEN_DECIMALS, INITIAL_SUPPLY } from "../utils/test-setup";
+import {
+  TestSetup,
+  TestContext,
+  INITIAL_SUPPLY,
+  TOKEN_2022_PROGRAM_ID,
+} from "../utils/test-setup";
+
+// 10% of the one billion token total supply, the most a 6-decimal token may vest
+const MAX_VESTING_AMOUNT = new BN(100_000_000).mul(new BN(10).pow(new BN(6)));
+// Lock and vesting periods together, 27 months of 30 days
+const FULL_UNLOCK_PERIOD = 27 * 30 * 86_400;
 
 describe("Create Token", () => {
   let ctx: TestContext;
 
   before(async () => {
     ctx = await TestSetup.initialize();
-    
-    // Initialize config first
-    const params = {
-      admin: ctx.admin.publicKey,
-      feeRecipient: ctx.admin.publicKey,
-      swapFeeBasisPoints: 30,
-      createTokenFeeBasisPoints: 100,
-      createPoolFeeLamports: new BN(1000000),
-    };
-
-    await ctx.program.methods
-      .initialize(params)
-      .accounts({
-        config: ctx.config,
-        admin: ctx.admin.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([ctx.admin])
-      .rpc();
   });
 
   describe("Success Cases", () => {
-    it("Should create AIW3 token successfully", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
-      );
+    it("Should mint the initial supply to the creator", async () => {
+      const token = await TestSetup.createToken(ctx, ctx.user1);
 
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
+      const mint = await getMint(ctx.connection, token.mint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(mint.supply.toString()).to.equal(INITIAL_SUPPLY.toString());
+      expect(mint.mintAuthority?.toString()).to.equal(token.swapPool.toString());
+      expect(mint.freezeAuthority).to.be.null;
+
+      const transferFee = getTransferFeeConfig(mint);
+      expect(transferFee?.newerTransferFee.transferFeeBasisPoints).to.equal(100);
+
+      const balance = await TestSetup.getTokenBalance(ctx.connection, token.creatorTokenAccount);
+      TestSetup.expectBNEqual(balance, INITIAL_SUPPLY);
+    });
 
-      const params = {
-        name: "AIW3 Token",
-        symbol: "AIW3",
-        uri: "https://example.com/aiw3.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 100, // 1%
-        maxFee: new BN(1000000), // 1 token max fee
-        tokenType: { aiw3: {} },
-      };
+    it("Should lock the vested part of the supply in escrow", async () => {
+      const vestingAmount = INITIAL_SUPPLY.divn(10);
+      const token = await TestSetup.createToken(ctx, ctx.user1, { vestingAmount });
 
-      await ctx.program.methods
-        .createToken(params)
-        .accounts({
-          config: ctx.config,
-          tokenMint: tokenMint.publicKey,
-          tokenInfo,
-          creator: creator.publicKey,
-          creatorTokenAccount: creatorAta,
-          feeRecipient: ctx.admin.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        })
-        .signers([creator, tokenMint])
-        .rpc();
+      const creatorBalance = await TestSetup.getTokenBalance(ctx.connection, token.creatorTokenAccount);
+      TestSetup.expectBNEqual(creatorBalance, INITIAL_SUPPLY.sub(vestingAmount));
 
-      // Verify token info was created correctly
-      const tokenInfoAccount = await ctx.program.account.tokenInfo.fetch(tokenInfo);
-      
-      expect(tokenInfoAccount.name).to.equal("AIW3 Token");
-      expect(tokenInfoAccount.symbol).to.equal("AIW3");
-      expect(tokenInfoAccount.uri).to.equal("https://example.com/aiw3.json");
-      expect(tokenInfoAccount.decimals).to.equal(TOKEN_DECIMALS);
-      expect(tokenInfoAccount.creator.toString()).to.equal(creator.publicKey.toString());
-      TestSetup.expectBNEqual(tokenInfoAccount.initialSupply, INITIAL_SUPPLY);
-      expect(tokenInfoAccount.transferFeeBasisPoints).to.equal(100);
-      TestSetup.expectBNEqual(tokenInfoAccount.maxFee, new BN(1000000));
+      const vaultBalance = await TestSetup.getTokenBalance(ctx.connection, token.vestingVault!);
+      TestSetup.expectBNEqual(vaultBalance, vestingAmount);
 
-      // Verify creator received initial supply
-      const creatorBalance = await TestSetup.getTokenBalance(ctx.connection, creatorAta);
-      TestSetup.expectBNEqual(creatorBalance, INITIAL_SUPPLY);
+      const schedule = await ctx.program.account.vestingSchedule.fetch(token.vestingSchedule!);
+      expect(schedule.beneficiary.toString()).to.equal(ctx.user1.publicKey.toString());
+      TestSetup.expectBNEqual(schedule.totalAmount, vestingAmount);
+      TestSetup.expectBNEqual(schedule.claimedAmount, new BN(0));
+      expect(schedule.endTimestamp.sub(schedule.startTimestamp).toNumber()).to.equal(FULL_UNLOCK_PERIOD);
     });
 
-    it("Should create AI Agent token successfully", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user2;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
-      );
+    it("Should seed the pool vault with the pool amount", async () => {
+      const poolAmount = INITIAL_SUPPLY.divn(4);
+      const vestingAmount = INITIAL_SUPPLY.divn(10);
+      const token = await TestSetup.createToken(ctx, ctx.user1, { poolAmount, vestingAmount });
 
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
+      const poolBalance = await TestSetup.getTokenBalance(ctx.connection, token.poolTokenVault);
+      TestSetup.expectBNEqual(poolBalance, poolAmount);
 
-      const params = {
-        name: "AI Agent Token",
-        symbol: "AIA",
-        uri: "https://example.com/aia.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 50, // 0.5%
-        maxFee: new BN(500000), // 0.5 token max fee
-        tokenType: { aiAgent: {} },
-      };
+      const swapPool = await ctx.program.account.swapPool.fetch(token.swapPool);
+      TestSetup.expectBNEqual(swapPool.aiAgentReserves, poolAmount);
+
+      // The creator keeps what the pool and the vesting escrow leave
+      const creatorBalance = await TestSetup.getTokenBalance(ctx.connection, token.creatorTokenAccount);
+      TestSetup.expectBNEqual(creatorBalance, INITIAL_SUPPLY.sub(poolAmount).sub(vestingAmount));
+    });
 
-      await ctx.program.methods
-        .createToken(params)
-        .accounts({
-          config: ctx.config,
-          tokenMint: tokenMint.publicKey,
-          tokenInfo,
-          creator: creator.publicKey,
-          creatorTokenAccount: creatorAta,
-          feeRecipient: ctx.admin.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        })
-        .signers([creator, tokenMint])
-        .rpc();
+    it("Should revoke the mint authority when asked to", async () => {
+      const token = await TestSetup.createToken(ctx, ctx.user1, { revokeMintAuthority: true });
 
-      // Verify token info was created correctly
-      const tokenInfoAccount = await ctx.program.account.tokenInfo.fetch(tokenInfo);
-      
-      expect(tokenInfoAccount.name).to.equal("AI Agent Token");
-      expect(tokenInfoAccount.symbol).to.equal("AIA");
-      expect(tokenInfoAccount.transferFeeBasisPoints).to.equal(50);
-      TestSetup.expectBNEqual(tokenInfoAccount.maxFee, new BN(500000));
+      const mint = await getMint(ctx.connection, token.mint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(mint.mintAuthority).to.be.null;
+      expect(mint.supply.toString()).to.equal(INITIAL_SUPPLY.toString());
     });
   });
 
   describe("Error Cases", () => {
     it("Should fail with invalid token name (too long)", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
+      await TestSetup.expectError(
+        () => TestSetup.createToken(ctx, ctx.user1, { name: "A".repeat(33) }),
+        "InvalidTokenMetadata"
       );
-
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
-
-      const params = {
-        name: "This is a very long token name that exceeds the maximum allowed length for token names", // Too long
-        symbol: "AIW3",
-        uri: "https://example.com/aiw3.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 100,
-        maxFee: new BN(1000000),
-        tokenType: { aiw3: {} },
-      };
-
-      try {
-        await ctx.program.methods
-          .createToken(params)
-          .accounts({
-            config: ctx.config,
-            tokenMint: tokenMint.publicKey,
-            tokenInfo,
-            creator: creator.publicKey,
-            creatorTokenAccount: creatorAta,
-            feeRecipient: ctx.admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-          })
-          .signers([creator, tokenMint])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidTokenName");
-      }
     });
 
-    it("Should fail with invalid decimals", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
+    it("Should fail with zero initial supply", async () => {
+      await TestSetup.expectError(
+        () => TestSetup.createToken(ctx, ctx.user1, { initialSupply: new BN(0) }),
+        "InvalidSupplySplit"
       );
+    });
 
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
+    it("Should fail when vesting more than the initial supply", async () => {
+      await TestSetup.expectError(
+        () =>
+          TestSetup.createToken(ctx, ctx.user1, {
+            initialSupply: new BN(1_000),
+            vestingAmount: new BN(1_001),
+          }),
+        "InvalidSupplySplit"
       );
-
-      const params = {
-        name: "Test Token",
-        symbol: "TEST",
-        uri: "https://example.com/test.json",
-        decimals: 255, // Invalid: too high
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 100,
-        maxFee: new BN(1000000),
-        tokenType: { aiw3: {} },
-      };
-
-      try {
-        await ctx.program.methods
-          .createToken(params)
-          .accounts({
-            config: ctx.config,
-            tokenMint: tokenMint.publicKey,
-            tokenInfo,
-            creator: creator.publicKey,
-            creatorTokenAccount: creatorAta,
-            feeRecipient: ctx.admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-          })
-          .signers([creator, tokenMint])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidDecimals");
-      }
     });
 
-    it("Should fail with zero initial supply", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
+    it("Should fail when the pool and vesting amounts exceed the initial supply", async () => {
+      await TestSetup.expectError(
+        () =>
+          TestSetup.createToken(ctx, ctx.user1, {
+            initialSupply: new BN(1_000),
+            poolAmount: new BN(600),
+            vestingAmount: new BN(401),
+          }),
+        "InvalidSupplySplit"
       );
+    });
 
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
+    it("Should fail when vesting more than the vesting allocation", async () => {
+      await TestSetup.expectError(
+        () =>
+          TestSetup.createToken(ctx, ctx.user1, {
+            initialSupply: MAX_VESTING_AMOUNT.muln(2),
+            vestingAmount: MAX_VESTING_AMOUNT.addn(1),
+          }),
+        "InvalidSupplySplit"
       );
-
-      const params = {
-        name: "Test Token",
-        symbol: "TEST",
-        uri: "https://example.com/test.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: new BN(0), // Invalid: zero supply
-        transferFeeBasisPoints: 100,
-        maxFee: new BN(1000000),
-        tokenType: { aiw3: {} },
-      };
-
-      try {
-        await ctx.program.methods
-          .createToken(params)
-          .accounts({
-            config: ctx.config,
-            tokenMint: tokenMint.publicKey,
-            tokenInfo,
-            creator: creator.publicKey,
-            creatorTokenAccount: creatorAta,
-            feeRecipient: ctx.admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-          })
-          .signers([creator, tokenMint])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidInitialSupply");
-      }
     });
 
     it("Should fail with invalid transfer fee basis points", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
+      await TestSetup.expectError(
+        () => TestSetup.createToken(ctx, ctx.user1, { transferFeeBasisPoints: 1001 }),
+        "InvalidFeeBasisPoints"
       );
-
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
-
-      const params = {
-        name: "Test Token",
-        symbol: "TEST",
-        uri: "https://example.com/test.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 10001, // Invalid: > 10000
-        maxFee: new BN(1000000),
-        tokenType: { aiw3: {} },
-      };
-
-      try {
-        await ctx.program.methods
-          .createToken(params)
-          .accounts({
-            config: ctx.config,
-            tokenMint: tokenMint.publicKey,
-            tokenInfo,
-            creator: creator.publicKey,
-            creatorTokenAccount: creatorAta,
-            feeRecipient: ctx.admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-          })
-          .signers([creator, tokenMint])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidFeeBasisPoints");
-      }
     });
   });
 
   describe("Edge Cases", () => {
-    it("Should create token with maximum supply", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
-      );
+    it("Should create token with the full vesting allocation", async () => {
+      const token = await TestSetup.createToken(ctx, ctx.user2, {
+        initialSupply: MAX_VESTING_AMOUNT,
+        vestingAmount: MAX_VESTING_AMOUNT,
+      });
 
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
+      const creatorBalance = await TestSetup.getTokenBalance(ctx.connection, token.creatorTokenAccount);
+      TestSetup.expectBNEqual(creatorBalance, new BN(0));
 
-      // Maximum supply for u64
-      const maxSupply = new BN("18446744073709551615"); // 2^64 - 1
-
-      const params = {
-        name: "Max Supply Token",
-        symbol: "MAX",
-        uri: "https://example.com/max.json",
-        decimals: 0, // Use 0 decimals to avoid overflow
-        initialSupply: maxSupply,
-        transferFeeBasisPoints: 0,
-        maxFee: new BN(0),
-        tokenType: { aiw3: {} },
-      };
-
-      await ctx.program.methods
-        .createToken(params)
-        .accounts({
-          config: ctx.config,
-          tokenMint: tokenMint.publicKey,
-          tokenInfo,
-          creator: creator.publicKey,
-          creatorTokenAccount: creatorAta,
-          feeRecipient: ctx.admin.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        })
-        .signers([creator, tokenMint])
-        .rpc();
+      const vaultBalance = await TestSetup.getTokenBalance(ctx.connection, token.vestingVault!);
+      TestSetup.expectBNEqual(vaultBalance, MAX_VESTING_AMOUNT);
+    });
 
-      const tokenInfoAccount = await ctx.program.account.tokenInfo.fetch(tokenInfo);
-      TestSetup.expectBNEqual(tokenInfoAccount.initialSupply, maxSupply);
+    it("Should scale the vesting allocation with the mint decimals", async () => {
+      // Over the cap for 6 decimals, a thousandth of it with 9
+      const vestingAmount = MAX_VESTING_AMOUNT.addn(1);
+      const token = await TestSetup.createToken(ctx, ctx.user2, {
+        decimals: 9,
+        initialSupply: vestingAmount,
+        vestingAmount,
+      });
+
+      const vaultBalance = await TestSetup.getTokenBalance(ctx.connection, token.vestingVault!);
+      TestSetup.expectBNEqual(vaultBalance, vestingAmount);
     });
 
     it("Should create token with zero transfer fee", async () => {
-      const tokenMint = Keypair.generate();
-      const creator = ctx.user1;
-      
-      const [tokenInfo] = PublicKey.findProgramAddressSync(
-        [Buffer.from("token_info"), tokenMint.publicKey.toBuffer()],
-        ctx.program.programId
-      );
-
-      const creatorAta = await getAssociatedTokenAddress(
-        tokenMint.publicKey,
-        creator.publicKey
-      );
-
-      const params = {
-        name: "Zero Fee Token",
-        symbol: "ZERO",
-        uri: "https://example.com/zero.json",
-        decimals: TOKEN_DECIMALS,
-        initialSupply: INITIAL_SUPPLY,
-        transferFeeBasisPoints: 0, // No transfer fee
+      const token = await TestSetup.createToken(ctx, ctx.user2, {
+        transferFeeBasisPoints: 0,
         maxFee: new BN(0),
-        tokenType: { aiAgent: {} },
-      };
-
-      await ctx.program.methods
-        .createToken(params)
-        .accounts({
-          config: ctx.config,
-          tokenMint: tokenMint.publicKey,
-          tokenInfo,
-          creator: creator.publicKey,
-          creatorTokenAccount: creatorAta,
-          feeRecipient: ctx.admin.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        })
-        .signers([creator, tokenMint])
-        .rpc();
+      });
 
-      const tokenInfoAccount = await ctx.program.account.tokenInfo.fetch(tokenInfo);
-      expect(tokenInfoAccount.transferFeeBasisPoints).to.equal(0);
-      TestSetup.expectBNEqual(tokenInfoAccount.maxFee, new BN(0));
+      const mint = await getMint(ctx.connection, token.mint, undefined, TOKEN_2022_PROGRAM_ID);
+      const transferFee = getTransferFeeConfig(mint);
+      expect(transferFee?.newerTransferFee.transferFeeBasisPoints).to.equal(0);
     });
   });
-}); 
\ No newline at end of file
+});
@@ -1,10 +1,16 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { Pump } from "../../target/types/pump";
-import { PublicKey, Keypair } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { expect } from "chai";
 import BN from "bn.js";
-import { TestSetup, TestContext } from "../utils/test-setup";
+import {
+  TestSetup,
+  TestContext,
+  SWAP_FEE_BASIS_POINTS,
+  NO_CONFIG_UPDATE,
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+} from "../utils/test-setup";
 
 describe("Initialize", () => {
   let ctx: TestContext;
@@ -15,195 +21,147 @@ describe("Initialize", () => {
 
   describe("Success Cases", () => {
     it("Should initialize config successfully", async () => {
-      const params = {
-        admin: ctx.admin.publicKey,
-        feeRecipient: ctx.admin.publicKey,
-        swapFeeBasisPoints: 30, // 0.3%
-        createTokenFeeBasisPoints: 100, // 1%
-        createPoolFeeLamports: new BN(1000000), // 0.001 SOL
-      };
+      const configAccount = await ctx.program.account.config.fetch(ctx.config);
+
+      expect(configAccount.admin.toString()).to.equal(ctx.admin.publicKey.toString());
+      expect(configAccount.feeRecipient.toString()).to.equal(ctx.admin.publicKey.toString());
+      expect(configAccount.operator.toString()).to.equal(ctx.admin.publicKey.toString());
+      expect(configAccount.tradeFeeBasisPoints).to.equal(SWAP_FEE_BASIS_POINTS);
+      expect(configAccount.isPaused).to.be.false;
+      expect(configAccount.version).to.equal(2);
+      TestSetup.expectBNEqual(configAccount.maxPriceAge, new BN(300));
+    });
 
+    it("Should let the admin update the config", async () => {
       await ctx.program.methods
-        .initialize(params)
+        .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: 50 })
         .accounts({
+          authority: ctx.admin.publicKey,
+          roleAccount: null,
           config: ctx.config,
-          admin: ctx.admin.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([ctx.admin])
         .rpc();
 
-      // Verify config was created correctly
-      const configAccount = await ctx.program.account.config.fetch(ctx.config);
-      
-      expect(configAccount.admin.toString()).to.equal(ctx.admin.publicKey.toString());
-      expect(configAccount.feeRecipient.toString()).to.equal(ctx.admin.publicKey.toString());
-      expect(configAccount.swapFeeBasisPoints).to.equal(30);
-      expect(configAccount.createTokenFeeBasisPoints).to.equal(100);
-      TestSetup.expectBNEqual(configAccount.createPoolFeeLamports, new BN(1000000));
+      let configAccount = await ctx.program.account.config.fetch(ctx.config);
+      expect(configAccount.tradeFeeBasisPoints).to.equal(50);
+
+      await ctx.program.methods
+        .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: SWAP_FEE_BASIS_POINTS })
+        .accounts({
+          authority: ctx.admin.publicKey,
+          roleAccount: null,
+          config: ctx.config,
+        })
+        .signers([ctx.admin])
+        .rpc();
+
+      configAccount = await ctx.program.account.config.fetch(ctx.config);
+      expect(configAccount.tradeFeeBasisPoints).to.equal(SWAP_FEE_BASIS_POINTS);
     });
   });
 
   describe("Error Cases", () => {
     it("Should fail when initializing config twice", async () => {
-      const params = {
-        admin: ctx.admin.publicKey,
-        feeRecipient: ctx.admin.publicKey,
-        swapFeeBasisPoints: 30,
-        createTokenFeeBasisPoints: 100,
-        createPoolFeeLamports: new BN(1000000),
-      };
+      const mopMint = await TestSetup.createFundedMint(ctx, ctx.admin.publicKey, new BN(1));
 
       try {
         await ctx.program.methods
-          .initialize(params)
+          .initialize({ tradeFeeBasisPoints: null, pumpFee: null, configUpdateDelay: null })
           .accounts({
-            config: ctx.config,
             admin: ctx.admin.publicKey,
+            config: ctx.config,
+            mopMint,
+            feeRecipient: ctx.admin.publicKey,
+            feeRecipientMopVault: getAssociatedTokenAddressSync(mopMint, ctx.admin.publicKey),
+            operator: ctx.admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([ctx.admin])
           .rpc();
-        
+
         expect.fail("Should have thrown an error");
       } catch (error) {
-        expect(error.message).to.include("already in use");
+        expect(String(error)).to.include("already in use");
       }
     });
 
-    it("Should fail with invalid fee basis points", async () => {
-      const newAdmin = Keypair.generate();
-      await TestSetup.fundAccount(ctx.connection, ctx.payer, newAdmin.publicKey, 1);
-
-      const [newConfig] = PublicKey.findProgramAddressSync(
-        [Buffer.from("config"), newAdmin.publicKey.toBuffer()],
-        ctx.program.programId
+    it("Should reject config updates from anyone but the admin", async () => {
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: 0 })
+            .accounts({
+              authority: ctx.user1.publicKey,
+              roleAccount: null,
+              config: ctx.config,
+            })
+            .signers([ctx.user1])
+            .rpc(),
+        "MissingRole"
       );
-
-      const params = {
-        admin: newAdmin.publicKey,
-        feeRecipient: newAdmin.publicKey,
-        swapFeeBasisPoints: 10001, // Invalid: > 10000
-        createTokenFeeBasisPoints: 100,
-        createPoolFeeLamports: new BN(1000000),
-      };
-
-      try {
-        await ctx.program.methods
-          .initialize(params)
-          .accounts({
-            config: newConfig,
-            admin: newAdmin.publicKey,
-            systemProgram: anchor.web3.SystemProgram.programId,
-          })
-          .signers([newAdmin])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidFeeBasisPoints");
-      }
     });
 
-    it("Should fail with zero admin address", async () => {
-      const newAdmin = Keypair.generate();
-      await TestSetup.fundAccount(ctx.connection, ctx.payer, newAdmin.publicKey, 1);
-
-      const [newConfig] = PublicKey.findProgramAddressSync(
-        [Buffer.from("config"), newAdmin.publicKey.toBuffer()],
-        ctx.program.programId
+    it("Should reject fee basis points above the maximum", async () => {
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: 1001 })
+            .accounts({
+              authority: ctx.admin.publicKey,
+              roleAccount: null,
+              config: ctx.config,
+            })
+            .signers([ctx.admin])
+            .rpc(),
+        "InvalidFeeBasisPoints"
       );
+    });
 
-      const params = {
-        admin: PublicKey.default, // Invalid: zero address
-        feeRecipient: newAdmin.publicKey,
-        swapFeeBasisPoints: 30,
-        createTokenFeeBasisPoints: 100,
-        createPoolFeeLamports: new BN(1000000),
-      };
-
-      try {
-        await ctx.program.methods
-          .initialize(params)
-          .accounts({
-            config: newConfig,
-            admin: newAdmin.publicKey,
-            systemProgram: anchor.web3.SystemProgram.programId,
-          })
-          .signers([newAdmin])
-          .rpc();
-        
-        expect.fail("Should have thrown an error");
-      } catch (error) {
-        expect(error.message).to.include("InvalidAdmin");
-      }
+    it("Should reject a zero fee recipient", async () => {
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .updateConfig({ ...NO_CONFIG_UPDATE, feeRecipient: PublicKey.default })
+            .accounts({
+              authority: ctx.admin.publicKey,
+              roleAccount: null,
+              config: ctx.config,
+            })
+            .signers([ctx.admin])
+            .rpc(),
+        "InvalidFeeRecipient"
+      );
     });
   });
 
   describe("Edge Cases", () => {
-    it("Should initialize with maximum valid fee basis points", async () => {
-      const newAdmin = Keypair.generate();
-      await TestSetup.fundAccount(ctx.connection, ctx.payer, newAdmin.publicKey, 1);
-
-      const [newConfig] = PublicKey.findProgramAddressSync(
-        [Buffer.from("config"), newAdmin.publicKey.toBuffer()],
-        ctx.program.programId
-      );
-
-      const params = {
-        admin: newAdmin.publicKey,
-        feeRecipient: newAdmin.publicKey,
-        swapFeeBasisPoints: 10000, // 100% - maximum valid
-        createTokenFeeBasisPoints: 10000, // 100% - maximum valid
-        createPoolFeeLamports: new BN(1000000),
-      };
-
+    it("Should accept the maximum price age and reject a longer one", async () => {
       await ctx.program.methods
-        .initialize(params)
+        .updateConfig({ ...NO_CONFIG_UPDATE, maxPriceAge: new BN(300) })
         .accounts({
-          config: newConfig,
-          admin: newAdmin.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          authority: ctx.admin.publicKey,
+          roleAccount: null,
+          config: ctx.config,
         })
-        .signers([newAdmin])
+        .signers([ctx.admin])
         .rpc();
 
-      const configAccount = await ctx.program.account.config.fetch(newConfig);
-      expect(configAccount.swapFeeBasisPoints).to.equal(10000);
-      expect(configAccount.createTokenFeeBasisPoints).to.equal(10000);
-    });
-
-    it("Should initialize with zero fee basis points", async () => {
-      const newAdmin = Keypair.generate();
-      await TestSetup.fundAccount(ctx.connection, ctx.payer, newAdmin.publicKey, 1);
-
-      const [newConfig] = PublicKey.findProgramAddressSync(
-        [Buffer.from("config"), newAdmin.publicKey.toBuffer()],
-        ctx.program.programId
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .updateConfig({ ...NO_CONFIG_UPDATE, maxPriceAge: new BN(301) })
+            .accounts({
+              authority: ctx.admin.publicKey,
+              roleAccount: null,
+              config: ctx.config,
+            })
+            .signers([ctx.admin])
+            .rpc(),
+        "InvalidPriceOracle"
       );
-
-      const params = {
-        admin: newAdmin.publicKey,
-        feeRecipient: newAdmin.publicKey,
-        swapFeeBasisPoints: 0, // 0% - minimum valid
-        createTokenFeeBasisPoints: 0, // 0% - minimum valid
-        createPoolFeeLamports: new BN(0), // Zero fee
-      };
-
-      await ctx.program.methods
-        .initialize(params)
-        .accounts({
-          config: newConfig,
-          admin: newAdmin.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .signers([newAdmin])
-        .rpc();
-
-      const configAccount = await ctx.program.account.config.fetch(newConfig);
-      expect(configAccount.swapFeeBasisPoints).to.equal(0);
-      expect(configAccount.createTokenFeeBasisPoints).to.equal(0);
-      TestSetup.expectBNEqual(configAccount.createPoolFeeLamports, new BN(0));
     });
   });
-}); 
\ No newline at end of file
+});
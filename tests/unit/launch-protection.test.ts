@@ -0,0 +1,82 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import BN from "bn.js";
+import {
+  TestSetup,
+  TestContext,
+  TestPool,
+  NO_LAUNCH_PROTECTION,
+  TOKEN_DECIMALS,
+} from "../utils/test-setup";
+
+const ONE_TOKEN = new BN(10).pow(new BN(TOKEN_DECIMALS));
+
+describe("Launch Protection", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+  let buyer: Keypair;
+  let launchBuyRecord: PublicKey;
+
+  const buy = (amountIn: BN, record: PublicKey | null = launchBuyRecord) =>
+    TestSetup.swap(
+      ctx,
+      pool,
+      buyer,
+      { amountIn, minimumAmountOut: new BN(0), inputIsAiw3: true },
+      { launchBuyRecord: record }
+    );
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1, {
+      launchProtection: {
+        tradingStartTimestamp: null,
+        launchProtectionSlots: new BN(9000),
+        maxBuyPerTx: ONE_TOKEN.muln(500),
+        maxBuyPerWallet: ONE_TOKEN.muln(600),
+        launchFeeBasisPoints: 1000,
+      },
+    });
+
+    buyer = ctx.user2;
+    await TestSetup.mintTokens(ctx, pool.aiw3Mint, buyer.publicKey, ONE_TOKEN.muln(10_000));
+    await TestSetup.tokenAccount(ctx, pool.aiAgentMint, buyer.publicKey);
+    launchBuyRecord = TestSetup.launchBuyRecordPda(ctx.program.programId, pool.swapPool, buyer.publicKey);
+  });
+
+  describe("Success Cases", () => {
+    it("Should record buys inside the launch window", async () => {
+      await buy(ONE_TOKEN.muln(300));
+
+      const record = await ctx.program.account.launchBuyRecord.fetch(launchBuyRecord);
+      expect(record.wallet.toString()).to.equal(buyer.publicKey.toString());
+      expect(record.amountBought.gtn(0)).to.be.true;
+      expect(record.amountBought.lt(ONE_TOKEN.muln(300))).to.be.true;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should cap a single buy", async () => {
+      await TestSetup.expectError(() => buy(ONE_TOKEN.muln(1_000)), "LaunchBuyLimitExceeded");
+    });
+
+    it("Should cap the total bought by a wallet", async () => {
+      await buy(ONE_TOKEN.muln(300));
+      await TestSetup.expectError(() => buy(ONE_TOKEN.muln(300)), "LaunchBuyLimitExceeded");
+    });
+
+    it("Should require the launch buy record while a wallet cap applies", async () => {
+      await TestSetup.expectError(() => buy(ONE_TOKEN, null), "LaunchBuyRecordRequired");
+    });
+
+    it("Should reject a launch fee above the maximum", async () => {
+      await TestSetup.expectError(
+        () =>
+          TestSetup.createWeightedPool(ctx, ctx.user1, {
+            launchProtection: { ...NO_LAUNCH_PROTECTION, launchFeeBasisPoints: 5001 },
+          }),
+        "InvalidLaunchProtection"
+      );
+    });
+  });
+});
@@ -0,0 +1,89 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, TestPool, INITIAL_LIQUIDITY } from "../utils/test-setup";
+
+describe("Liquidity", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+  let provider: Keypair;
+
+  const SHARES = INITIAL_LIQUIDITY.divn(2);
+
+  const addLiquidity = (user: Keypair, shares: BN, maxAmount: BN = shares) =>
+    TestSetup.addLiquidity(ctx, pool, user, { shares, maxAiw3Amount: maxAmount, maxAiAgentAmount: maxAmount });
+
+  const removeLiquidity = (user: Keypair, shares: BN, minAmount: BN = new BN(0)) =>
+    TestSetup.removeLiquidity(ctx, pool, user, { shares, minAiw3Amount: minAmount, minAiAgentAmount: minAmount });
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+
+    provider = ctx.user2;
+    await TestSetup.mintTokens(ctx, pool.aiw3Mint, provider.publicKey, INITIAL_LIQUIDITY);
+    await TestSetup.mintTokens(ctx, pool.aiAgentMint, provider.publicKey, INITIAL_LIQUIDITY);
+  });
+
+  describe("Success Cases", () => {
+    it("Should lock the creator's initial liquidity in their position", async () => {
+      const position = await ctx.program.account.liquidityPosition.fetch(pool.creatorLiquidityPosition);
+      TestSetup.expectBNEqual(position.shares, INITIAL_LIQUIDITY);
+      TestSetup.expectBNEqual(position.lockedShares, INITIAL_LIQUIDITY);
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      TestSetup.expectBNEqual(poolAccount.totalShares, INITIAL_LIQUIDITY);
+    });
+
+    it("Should take a proportional deposit without moving the price", async () => {
+      const quoteBefore = await TestSetup.quote(ctx, pool, new BN(1_000_000), true);
+
+      await addLiquidity(provider, SHARES);
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      TestSetup.expectBNEqual(poolAccount.totalShares, INITIAL_LIQUIDITY.add(SHARES));
+      TestSetup.expectBNEqual(poolAccount.aiw3Reserves, INITIAL_LIQUIDITY.add(SHARES));
+      TestSetup.expectBNEqual(poolAccount.aiAgentReserves, INITIAL_LIQUIDITY.add(SHARES));
+
+      // Deeper reserves mean less price impact, never a worse price
+      const quoteAfter = await TestSetup.quote(ctx, pool, new BN(1_000_000), true);
+      expect(quoteAfter.gte(quoteBefore)).to.be.true;
+    });
+
+    it("Should return a proportional withdrawal and close the emptied position", async () => {
+      await removeLiquidity(provider, SHARES, SHARES);
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      TestSetup.expectBNEqual(poolAccount.totalShares, INITIAL_LIQUIDITY);
+
+      const aiAgentBalance = await TestSetup.getTokenBalance(
+        ctx.connection,
+        await TestSetup.tokenAccount(ctx, pool.aiAgentMint, provider.publicKey)
+      );
+      TestSetup.expectBNEqual(aiAgentBalance, INITIAL_LIQUIDITY);
+
+      const position = TestSetup.liquidityPositionPda(ctx.program.programId, pool.swapPool, provider.publicKey);
+      expect(await ctx.connection.getAccountInfo(position)).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject a deposit above the caller's maximum", async () => {
+      await TestSetup.expectError(() => addLiquidity(provider, SHARES, SHARES.subn(1)), "SlippageExceeded");
+    });
+
+    it("Should reject a withdrawal below the caller's minimum", async () => {
+      await addLiquidity(provider, SHARES);
+
+      try {
+        await TestSetup.expectError(() => removeLiquidity(provider, SHARES, SHARES.addn(1)), "SlippageExceeded");
+      } finally {
+        await removeLiquidity(provider, SHARES);
+      }
+    });
+
+    it("Should keep the creator's initial liquidity locked", async () => {
+      await TestSetup.expectError(() => removeLiquidity(ctx.user1, new BN(1)), "InsufficientLiquidity");
+    });
+  });
+});
@@ -0,0 +1,103 @@
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, TestPool, TOKEN_DECIMALS } from "../utils/test-setup";
+
+// Least native SOL a swap may pay in
+const MINIMUM_SOL_BUY_AMOUNT = new BN(2_040_000);
+const SWAP_AMOUNT = new BN(LAMPORTS_PER_SOL / 10);
+
+describe("Native SOL", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+  let trader: Keypair;
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1, {
+      nativeSol: true,
+      aiw3Amount: new BN(LAMPORTS_PER_SOL),
+    });
+
+    // Create the trader's token accounts up front so lamport deltas are exact
+    trader = ctx.user2;
+    await TestSetup.tokenAccount(ctx, NATIVE_MINT, trader.publicKey);
+    await TestSetup.mintTokens(ctx, pool.aiAgentMint, trader.publicKey, new BN(10).pow(new BN(TOKEN_DECIMALS + 4)));
+  });
+
+  const nativeSolAccount = () => TestSetup.nativeSolPda(ctx.program.programId, trader.publicKey);
+
+  describe("Success Cases", () => {
+    it("Should buy with native SOL without touching the trader's wSOL account", async () => {
+      const wsolAccount = await TestSetup.tokenAccount(ctx, NATIVE_MINT, trader.publicKey);
+      const aiAgentAccount = await TestSetup.tokenAccount(ctx, pool.aiAgentMint, trader.publicKey);
+      const lamportsBefore = await ctx.connection.getBalance(trader.publicKey);
+      const aiAgentBefore = await TestSetup.getTokenBalance(ctx.connection, aiAgentAccount);
+
+      const quoted = await TestSetup.quote(ctx, pool, SWAP_AMOUNT, true);
+      await TestSetup.swap(ctx, pool, trader, {
+        amountIn: SWAP_AMOUNT,
+        minimumAmountOut: quoted,
+        inputIsAiw3: true,
+        nativeSol: true,
+      });
+
+      // The temporary account is closed and its rent refunded
+      const lamportsAfter = await ctx.connection.getBalance(trader.publicKey);
+      expect(lamportsBefore - lamportsAfter).to.equal(SWAP_AMOUNT.toNumber());
+      expect(await ctx.connection.getAccountInfo(nativeSolAccount())).to.be.null;
+
+      const aiAgentAfter = await TestSetup.getTokenBalance(ctx.connection, aiAgentAccount);
+      TestSetup.expectBNEqual(aiAgentAfter.sub(aiAgentBefore), quoted);
+      TestSetup.expectBNEqual(await TestSetup.getTokenBalance(ctx.connection, wsolAccount), new BN(0));
+    });
+
+    it("Should sell for native SOL", async () => {
+      const amountIn = new BN(10).pow(new BN(TOKEN_DECIMALS + 2));
+      const lamportsBefore = await ctx.connection.getBalance(trader.publicKey);
+
+      const quoted = await TestSetup.quote(ctx, pool, amountIn, false);
+      await TestSetup.swap(ctx, pool, trader, {
+        amountIn,
+        minimumAmountOut: quoted,
+        inputIsAiw3: false,
+        nativeSol: true,
+      });
+
+      const lamportsAfter = await ctx.connection.getBalance(trader.publicKey);
+      expect(lamportsAfter - lamportsBefore).to.equal(quoted.toNumber());
+      expect(await ctx.connection.getAccountInfo(nativeSolAccount())).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject native SOL input below the minimum buy", async () => {
+      await TestSetup.expectError(
+        () =>
+          TestSetup.swap(ctx, pool, trader, {
+            amountIn: MINIMUM_SOL_BUY_AMOUNT.subn(1),
+            minimumAmountOut: new BN(0),
+            inputIsAiw3: true,
+            nativeSol: true,
+          }),
+        "AmountTooSmall"
+      );
+    });
+
+    it("Should reject native SOL on pools not quoted in wrapped SOL", async () => {
+      const tokenPool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+
+      await TestSetup.expectError(
+        () =>
+          TestSetup.swap(ctx, tokenPool, trader, {
+            amountIn: SWAP_AMOUNT,
+            minimumAmountOut: new BN(0),
+            inputIsAiw3: true,
+            nativeSol: true,
+          }),
+        "NativeSolNotSupported"
+      );
+    });
+  });
+});
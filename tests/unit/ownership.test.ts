@@ -0,0 +1,96 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { TestSetup, TestContext } from "../utils/test-setup";
+
+describe("Ownership Transfer", () => {
+  let ctx: TestContext;
+
+  const transferOwnership = (admin: Keypair, newAdmin: PublicKey) =>
+    ctx.program.methods
+      .transferOwnership({ newAdmin })
+      .accounts({ admin: admin.publicKey, config: ctx.config })
+      .signers([admin])
+      .rpc();
+
+  const acceptOwnership = (pendingAdmin: Keypair) =>
+    ctx.program.methods
+      .acceptOwnership()
+      .accounts({ pendingAdmin: pendingAdmin.publicKey, config: ctx.config })
+      .signers([pendingAdmin])
+      .rpc();
+
+  const cancelOwnershipTransfer = (admin: Keypair) =>
+    ctx.program.methods
+      .cancelOwnershipTransfer()
+      .accounts({ admin: admin.publicKey, config: ctx.config })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+  });
+
+  describe("Success Cases", () => {
+    it("Should only hand over the config once the new admin accepts", async () => {
+      await transferOwnership(ctx.admin, ctx.user1.publicKey);
+
+      let config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.admin.toString()).to.equal(ctx.admin.publicKey.toString());
+      expect(config.pendingAdmin.toString()).to.equal(ctx.user1.publicKey.toString());
+
+      await acceptOwnership(ctx.user1);
+      try {
+        config = await ctx.program.account.config.fetch(ctx.config);
+        expect(config.admin.toString()).to.equal(ctx.user1.publicKey.toString());
+        expect(config.pendingAdmin.toString()).to.equal(PublicKey.default.toString());
+      } finally {
+        // Hand the config back so the other test files keep their admin
+        await transferOwnership(ctx.user1, ctx.admin.publicKey);
+        await acceptOwnership(ctx.admin);
+      }
+
+      config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.admin.toString()).to.equal(ctx.admin.publicKey.toString());
+    });
+
+    it("Should let the admin cancel a pending transfer", async () => {
+      await transferOwnership(ctx.admin, ctx.user1.publicKey);
+      await cancelOwnershipTransfer(ctx.admin);
+
+      const config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.pendingAdmin.toString()).to.equal(PublicKey.default.toString());
+
+      await TestSetup.expectError(() => acceptOwnership(ctx.user1), "NoPendingAdmin");
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject a transfer from anyone but the admin", async () => {
+      await TestSetup.expectError(
+        () => transferOwnership(ctx.user1, ctx.user1.publicKey),
+        "NotAdmin"
+      );
+    });
+
+    it("Should reject a transfer to the current admin", async () => {
+      await TestSetup.expectError(
+        () => transferOwnership(ctx.admin, ctx.admin.publicKey),
+        "InvalidOwner"
+      );
+    });
+
+    it("Should reject an accept from anyone but the pending admin", async () => {
+      await transferOwnership(ctx.admin, ctx.user1.publicKey);
+
+      try {
+        await TestSetup.expectError(() => acceptOwnership(ctx.user2), "InvalidOwner");
+      } finally {
+        await cancelOwnershipTransfer(ctx.admin);
+      }
+    });
+
+    it("Should reject cancelling when no transfer is pending", async () => {
+      await TestSetup.expectError(() => cancelOwnershipTransfer(ctx.admin), "NoPendingAdmin");
+    });
+  });
+});
@@ -0,0 +1,64 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, TestPool, TOKEN_DECIMALS } from "../utils/test-setup";
+
+describe("Emergency Pause", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+
+  const setPaused = (authority: Keypair, isPaused: boolean) =>
+    ctx.program.methods
+      .setPaused({ isPaused })
+      .accounts({
+        authority: authority.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+      })
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+    await TestSetup.mintTokens(ctx, pool.aiw3Mint, ctx.user2.publicKey, new BN(10).pow(new BN(TOKEN_DECIMALS)));
+    await TestSetup.tokenAccount(ctx, pool.aiAgentMint, ctx.user2.publicKey);
+  });
+
+  describe("Success Cases", () => {
+    it("Should block swaps and token creation while paused", async () => {
+      await setPaused(ctx.admin, true);
+
+      try {
+        const config = await ctx.program.account.config.fetch(ctx.config);
+        expect(config.isPaused).to.be.true;
+
+        await TestSetup.expectError(
+          () =>
+            TestSetup.swap(ctx, pool, ctx.user2, {
+              amountIn: new BN(1_000),
+              minimumAmountOut: new BN(0),
+              inputIsAiw3: true,
+            }),
+          "ProtocolPaused"
+        );
+        await TestSetup.expectError(() => TestSetup.createToken(ctx, ctx.user2), "ProtocolPaused");
+      } finally {
+        await setPaused(ctx.admin, false);
+      }
+
+      const config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.isPaused).to.be.false;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject a pause from anyone but the admin or guardian", async () => {
+      await TestSetup.expectError(() => setPaused(ctx.user1, true), "InvalidGuardian");
+    });
+
+    it("Should reject an unpause from anyone but the admin", async () => {
+      await TestSetup.expectError(() => setPaused(ctx.user1, false), "InvalidGuardian");
+    });
+  });
+});
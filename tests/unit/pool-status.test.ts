@@ -0,0 +1,50 @@
+import { expect } from "chai";
+import { TestSetup, TestContext, TestPool } from "../utils/test-setup";
+
+describe("Set Pool Status", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+  });
+
+  describe("Success Cases", () => {
+    it("Should let the admin pause and resume a pool", async () => {
+      await TestSetup.setPoolStatus(ctx, pool, false);
+      let poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      expect(poolAccount.isActive).to.be.false;
+
+      await TestSetup.setPoolStatus(ctx, pool, true);
+      poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      expect(poolAccount.isActive).to.be.true;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject a status change from anyone but the admin or operator", async () => {
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .setPoolStatus({ isActive: false, reasonCode: 1 })
+            .accounts({
+              authority: ctx.user1.publicKey,
+              roleAccount: null,
+              config: ctx.config,
+              swapPool: pool.swapPool,
+            })
+            .signers([ctx.user1])
+            .rpc(),
+        "InvalidOperator"
+      );
+    });
+
+    it("Should reject setting the status the pool already has", async () => {
+      await TestSetup.expectError(
+        () => TestSetup.setPoolStatus(ctx, pool, true),
+        "InvalidPoolState"
+      );
+    });
+  });
+});
@@ -0,0 +1,96 @@
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { TestSetup, TestContext, TestPool } from "../utils/test-setup";
+
+const ROLE_FEE_MANAGER = 1 << 0;
+const ROLE_POOL_MANAGER = 1 << 1;
+
+describe("Roles", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+  let member: Keypair;
+
+  const grantRole = (admin: Keypair, roles: number) =>
+    ctx.program.methods
+      .grantRole({ member: member.publicKey, roles })
+      .accounts({
+        admin: admin.publicKey,
+        config: ctx.config,
+        roleAccount: TestSetup.rolePda(ctx.program.programId, member.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+  const revokeRole = (roles: number) =>
+    ctx.program.methods
+      .revokeRole({ roles })
+      .accounts({
+        admin: ctx.admin.publicKey,
+        config: ctx.config,
+        roleAccount: TestSetup.rolePda(ctx.program.programId, member.publicKey),
+      })
+      .signers([ctx.admin])
+      .rpc();
+
+  const setPoolStatusAsMember = (isActive: boolean) =>
+    ctx.program.methods
+      .setPoolStatus({ isActive, reasonCode: 0 })
+      .accounts({
+        authority: member.publicKey,
+        roleAccount: TestSetup.rolePda(ctx.program.programId, member.publicKey),
+        config: ctx.config,
+        swapPool: pool.swapPool,
+      })
+      .signers([member])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+    member = Keypair.generate();
+    await TestSetup.fundAccount(ctx.connection, ctx.payer, member.publicKey, 1);
+  });
+
+  describe("Success Cases", () => {
+    it("Should let a pool manager pause and resume pools", async () => {
+      await grantRole(ctx.admin, ROLE_POOL_MANAGER);
+
+      const roleAccount = await ctx.program.account.roleAccount.fetch(
+        TestSetup.rolePda(ctx.program.programId, member.publicKey)
+      );
+      expect(roleAccount.member.toString()).to.equal(member.publicKey.toString());
+      expect(roleAccount.roles).to.equal(ROLE_POOL_MANAGER);
+
+      await setPoolStatusAsMember(false);
+      await setPoolStatusAsMember(true);
+    });
+
+    it("Should close the role account once the last role is revoked", async () => {
+      await grantRole(ctx.admin, ROLE_FEE_MANAGER);
+      await revokeRole(ROLE_POOL_MANAGER);
+
+      const roleAccount = await ctx.program.account.roleAccount.fetch(
+        TestSetup.rolePda(ctx.program.programId, member.publicKey)
+      );
+      expect(roleAccount.roles).to.equal(ROLE_FEE_MANAGER);
+      await TestSetup.expectError(() => setPoolStatusAsMember(false), "InvalidOperator");
+
+      await revokeRole(ROLE_FEE_MANAGER);
+      const info = await ctx.connection.getAccountInfo(
+        TestSetup.rolePda(ctx.program.programId, member.publicKey)
+      );
+      expect(info).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject grants from anyone but the admin", async () => {
+      await TestSetup.expectError(() => grantRole(ctx.user1, ROLE_POOL_MANAGER), "NotAdmin");
+    });
+
+    it("Should reject unknown role bits", async () => {
+      await TestSetup.expectError(() => grantRole(ctx.admin, 1 << 4), "InvalidRole");
+    });
+  });
+});
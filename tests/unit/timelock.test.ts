@@ -0,0 +1,177 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import BN from "bn.js";
+import {
+  TestSetup,
+  TestContext,
+  NO_CONFIG_UPDATE,
+  SWAP_FEE_BASIS_POINTS,
+} from "../utils/test-setup";
+
+const ROLE_FEE_MANAGER = 1 << 0;
+
+describe("Timelocked Config Updates", () => {
+  let ctx: TestContext;
+  let pendingConfigUpdate: PublicKey;
+
+  const queueConfigUpdate = (params: object) =>
+    ctx.program.methods
+      .queueConfigUpdate({ ...NO_CONFIG_UPDATE, ...params })
+      .accounts({
+        authority: ctx.admin.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+        pendingConfigUpdate,
+        previousPayer: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.admin])
+      .rpc();
+
+  const executeConfigUpdate = (payer: PublicKey = ctx.admin.publicKey) =>
+    ctx.program.methods
+      .executeConfigUpdate()
+      .accounts({
+        executor: ctx.user1.publicKey,
+        config: ctx.config,
+        pendingConfigUpdate,
+        proposerRoleAccount: null,
+        payer,
+      })
+      .signers([ctx.user1])
+      .rpc();
+
+  const cancelConfigUpdate = (admin: Keypair, payer: PublicKey = ctx.admin.publicKey) =>
+    ctx.program.methods
+      .cancelConfigUpdate()
+      .accounts({
+        admin: admin.publicKey,
+        config: ctx.config,
+        pendingConfigUpdate,
+        payer,
+      })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pendingConfigUpdate = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_config_update")],
+      ctx.program.programId
+    )[0];
+  });
+
+  describe("Success Cases", () => {
+    it("Should let anyone execute a queued update once it is due", async () => {
+      await queueConfigUpdate({ tradeFeeBasisPoints: 40 });
+      await executeConfigUpdate();
+
+      const config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.tradeFeeBasisPoints).to.equal(40);
+      expect(await ctx.connection.getAccountInfo(pendingConfigUpdate)).to.be.null;
+
+      await ctx.program.methods
+        .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: SWAP_FEE_BASIS_POINTS })
+        .accounts({ authority: ctx.admin.publicKey, roleAccount: null, config: ctx.config })
+        .signers([ctx.admin])
+        .rpc();
+    });
+
+    it("Should hold updates back until the delay has passed", async () => {
+      await ctx.program.methods
+        .updateConfig({ ...NO_CONFIG_UPDATE, configUpdateDelay: new BN(2) })
+        .accounts({ authority: ctx.admin.publicKey, roleAccount: null, config: ctx.config })
+        .signers([ctx.admin])
+        .rpc();
+
+      // Direct updates are closed while a delay is set
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .updateConfig({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: 40 })
+            .accounts({ authority: ctx.admin.publicKey, roleAccount: null, config: ctx.config })
+            .signers([ctx.admin])
+            .rpc(),
+        "TimelockRequired"
+      );
+
+      await queueConfigUpdate({ configUpdateDelay: new BN(0) });
+      await TestSetup.expectError(() => executeConfigUpdate(), "TimelockNotElapsed");
+
+      await TestSetup.sleep(5_000);
+      await executeConfigUpdate();
+
+      const config = await ctx.program.account.config.fetch(ctx.config);
+      TestSetup.expectBNEqual(config.configUpdateDelay, new BN(0));
+    });
+
+    it("Should let the admin cancel a queued update", async () => {
+      await queueConfigUpdate({ tradeFeeBasisPoints: 40 });
+      await cancelConfigUpdate(ctx.admin);
+
+      const config = await ctx.program.account.config.fetch(ctx.config);
+      expect(config.tradeFeeBasisPoints).to.equal(SWAP_FEE_BASIS_POINTS);
+      expect(await ctx.connection.getAccountInfo(pendingConfigUpdate)).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject queueing an update that could never be executed", async () => {
+      await TestSetup.expectError(
+        () => queueConfigUpdate({ tradeFeeBasisPoints: 1001 }),
+        "InvalidFeeBasisPoints"
+      );
+    });
+
+    it("Should reject an update whose proposer lost their role before execution", async () => {
+      const feeManager = ctx.user2;
+      const roleAccount = TestSetup.rolePda(ctx.program.programId, feeManager.publicKey);
+      await ctx.program.methods
+        .grantRole({ member: feeManager.publicKey, roles: ROLE_FEE_MANAGER })
+        .accounts({
+          admin: ctx.admin.publicKey,
+          config: ctx.config,
+          roleAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.admin])
+        .rpc();
+
+      await ctx.program.methods
+        .queueConfigUpdate({ ...NO_CONFIG_UPDATE, tradeFeeBasisPoints: 40 })
+        .accounts({
+          authority: feeManager.publicKey,
+          roleAccount,
+          config: ctx.config,
+          pendingConfigUpdate,
+          previousPayer: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([feeManager])
+        .rpc();
+
+      // Revoking the last role closes the role account
+      await ctx.program.methods
+        .revokeRole({ roles: ROLE_FEE_MANAGER })
+        .accounts({ admin: ctx.admin.publicKey, config: ctx.config, roleAccount })
+        .signers([ctx.admin])
+        .rpc();
+
+      try {
+        await TestSetup.expectError(() => executeConfigUpdate(feeManager.publicKey), "MissingRole");
+      } finally {
+        await cancelConfigUpdate(ctx.admin, feeManager.publicKey);
+      }
+    });
+
+    it("Should reject cancellation by anyone but the admin", async () => {
+      await queueConfigUpdate({ tradeFeeBasisPoints: 40 });
+
+      try {
+        await TestSetup.expectError(() => cancelConfigUpdate(ctx.user1), "NotAdmin");
+      } finally {
+        await cancelConfigUpdate(ctx.admin);
+      }
+    });
+  });
+});
@@ -0,0 +1,91 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { transferCheckedWithFee } from "@solana/spl-token";
+import BN from "bn.js";
+import {
+  TestSetup,
+  TestContext,
+  TestToken,
+  TOKEN_DECIMALS,
+  TOKEN_2022_PROGRAM_ID,
+} from "../utils/test-setup";
+
+// 10 tokens at the default 1% fee, well under the 1 token fee cap
+const TRANSFER_AMOUNT = new BN(10_000_000);
+const TRANSFER_FEE = new BN(100_000);
+
+describe("Transfer Fees", () => {
+  let ctx: TestContext;
+  let token: TestToken;
+  let holderAccount: PublicKey;
+  let feeRecipientAccount: PublicKey;
+
+  const harvestTransferFees = (sources: PublicKey[]) =>
+    ctx.program.methods
+      .harvestTransferFees()
+      .accounts({ mint: token.mint, tokenProgram2022: TOKEN_2022_PROGRAM_ID })
+      .remainingAccounts(sources.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+  const withdrawTransferFees = (authority: Keypair) =>
+    ctx.program.methods
+      .withdrawTransferFees()
+      .accounts({
+        authority: authority.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+        mint: token.mint,
+        feeRecipientTokenAccount: feeRecipientAccount,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    token = await TestSetup.createToken(ctx, ctx.user1);
+    holderAccount = await TestSetup.tokenAccount(ctx, token.mint, ctx.user2.publicKey, TOKEN_2022_PROGRAM_ID);
+    feeRecipientAccount = await TestSetup.tokenAccount(
+      ctx, token.mint, ctx.admin.publicKey, TOKEN_2022_PROGRAM_ID
+    );
+
+    // The fee is withheld in the receiving account
+    await transferCheckedWithFee(
+      ctx.connection,
+      ctx.payer,
+      token.creatorTokenAccount,
+      token.mint,
+      holderAccount,
+      ctx.user1,
+      BigInt(TRANSFER_AMOUNT.toString()),
+      TOKEN_DECIMALS,
+      BigInt(TRANSFER_FEE.toString()),
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
+  describe("Success Cases", () => {
+    it("Should harvest withheld fees and pay them to the fee recipient", async () => {
+      await harvestTransferFees([holderAccount]);
+      await withdrawTransferFees(ctx.admin);
+
+      const balance = await TestSetup.getTokenBalance(ctx.connection, feeRecipientAccount);
+      TestSetup.expectBNEqual(balance, TRANSFER_FEE);
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject a harvest without source accounts", async () => {
+      await TestSetup.expectError(() => harvestTransferFees([]), "NothingToClaim");
+    });
+
+    it("Should reject a withdrawal when nothing is withheld", async () => {
+      await TestSetup.expectError(() => withdrawTransferFees(ctx.admin), "NothingToClaim");
+    });
+
+    it("Should reject withdrawals from anyone but the admin or a fee manager", async () => {
+      await TestSetup.expectError(() => withdrawTransferFees(ctx.user1), "NotAdmin");
+    });
+  });
+});
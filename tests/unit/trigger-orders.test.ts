@@ -0,0 +1,65 @@
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import BN from "bn.js";
+import { TestSetup, TestContext, TestPool } from "../utils/test-setup";
+
+describe("Trigger Orders", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject orders on weighted pools, which have no oracle price", async () => {
+      const owner = ctx.user1;
+      const orderId = new BN(0);
+      const triggerOrder = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("trigger_order"),
+          pool.swapPool.toBuffer(),
+          owner.publicKey.toBuffer(),
+          orderId.toArrayLike(Buffer, "le", 8),
+        ],
+        ctx.program.programId
+      )[0];
+      const orderVault = PublicKey.findProgramAddressSync(
+        [Buffer.from("trigger_order_vault"), triggerOrder.toBuffer()],
+        ctx.program.programId
+      )[0];
+
+      await TestSetup.expectError(
+        () =>
+          ctx.program.methods
+            .placeTriggerOrder({
+              orderId,
+              kind: { stopLoss: {} },
+              amountIn: new BN(1_000_000),
+              triggerPrice: new BN(1_000),
+              minAmountOut: new BN(1),
+              maxPriceAge: null,
+            })
+            .accounts({
+              owner: owner.publicKey,
+              config: ctx.config,
+              aiw3TokenMint: pool.aiw3Mint,
+              aiAgentTokenMint: pool.aiAgentMint,
+              swapPool: pool.swapPool,
+              triggerOrder,
+              orderVault,
+              ownerAiAgentAccount: getAssociatedTokenAddressSync(pool.aiAgentMint, owner.publicKey),
+              ownerAiw3Account: getAssociatedTokenAddressSync(pool.aiw3Mint, owner.publicKey),
+              aiw3TokenProgram: pool.aiw3TokenProgram,
+              aiAgentTokenProgram: pool.aiAgentTokenProgram,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([owner])
+            .rpc(),
+        "InvalidTriggerOrder"
+      );
+    });
+  });
+});
@@ -0,0 +1,88 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, TestPool } from "../utils/test-setup";
+
+// UpdatePoolParams leaving every setting unchanged
+const NO_POOL_UPDATE = {
+  swapFeeBasisPoints: null,
+  maxPriceImpactBasisPoints: null,
+  minSwapAmount: null,
+  launchProtection: null,
+};
+
+describe("Update Pool", () => {
+  let ctx: TestContext;
+  let pool: TestPool;
+
+  const updatePool = (authority: Keypair, params: object) =>
+    ctx.program.methods
+      .updatePool({ ...NO_POOL_UPDATE, ...params })
+      .accounts({
+        authority: authority.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+        swapPool: pool.swapPool,
+      })
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+    pool = await TestSetup.createWeightedPool(ctx, ctx.user1);
+  });
+
+  describe("Success Cases", () => {
+    it("Should let the admin update the pool parameters", async () => {
+      await updatePool(ctx.admin, {
+        swapFeeBasisPoints: 100,
+        maxPriceImpactBasisPoints: 1000,
+        minSwapAmount: new BN(5_000),
+      });
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      expect(poolAccount.swapFeeBasisPoints).to.equal(100);
+      expect(poolAccount.maxPriceImpactBasisPoints).to.equal(1000);
+      TestSetup.expectBNEqual(poolAccount.minSwapAmount, new BN(5_000));
+    });
+
+    it("Should leave unset parameters unchanged", async () => {
+      await updatePool(ctx.admin, { swapFeeBasisPoints: 50 });
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      expect(poolAccount.swapFeeBasisPoints).to.equal(50);
+      expect(poolAccount.maxPriceImpactBasisPoints).to.equal(1000);
+      TestSetup.expectBNEqual(poolAccount.minSwapAmount, new BN(5_000));
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject updates from anyone but the admin", async () => {
+      await TestSetup.expectError(
+        () => updatePool(ctx.user2, { swapFeeBasisPoints: 0 }),
+        "InvalidOwner"
+      );
+    });
+
+    it("Should reject a swap fee above the maximum", async () => {
+      await TestSetup.expectError(
+        () => updatePool(ctx.admin, { swapFeeBasisPoints: 1001 }),
+        "InvalidSwapParams"
+      );
+    });
+
+    it("Should reject a zero max price impact", async () => {
+      await TestSetup.expectError(
+        () => updatePool(ctx.admin, { maxPriceImpactBasisPoints: 0 }),
+        "InvalidSwapParams"
+      );
+    });
+
+    it("Should reject a zero minimum swap amount", async () => {
+      await TestSetup.expectError(
+        () => updatePool(ctx.admin, { minSwapAmount: new BN(0) }),
+        "InvalidSwapParams"
+      );
+    });
+  });
+});
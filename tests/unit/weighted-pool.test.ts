@@ -0,0 +1,50 @@
+import { expect } from "chai";
+import BN from "bn.js";
+import { TestSetup, TestContext, TOKEN_DECIMALS } from "../utils/test-setup";
+
+const SWAP_AMOUNT = new BN(1_000).mul(new BN(10).pow(new BN(TOKEN_DECIMALS)));
+
+describe("Weighted Pools", () => {
+  let ctx: TestContext;
+
+  before(async () => {
+    ctx = await TestSetup.initialize();
+  });
+
+  describe("Success Cases", () => {
+    it("Should price an 80/20 pool by its weights", async () => {
+      const pool = await TestSetup.createWeightedPool(ctx, ctx.user1, {
+        aiw3Weight: 8000,
+        aiAgentWeight: 2000,
+      });
+
+      const poolAccount = await ctx.program.account.swapPool.fetch(pool.swapPool);
+      expect(poolAccount.aiw3Weight).to.equal(8000);
+      expect(poolAccount.aiAgentWeight).to.equal(2000);
+
+      // With equal reserves one AIW3 is worth four AI Agent tokens
+      const aiAgentOut = await TestSetup.quote(ctx, pool, SWAP_AMOUNT, true);
+      expect(aiAgentOut.gt(SWAP_AMOUNT.muln(3))).to.be.true;
+      expect(aiAgentOut.lt(SWAP_AMOUNT.muln(4))).to.be.true;
+
+      const aiw3Out = await TestSetup.quote(ctx, pool, SWAP_AMOUNT, false);
+      expect(aiw3Out.lt(SWAP_AMOUNT.divn(4))).to.be.true;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Should reject weights that do not add up to 100%", async () => {
+      await TestSetup.expectError(
+        () => TestSetup.createWeightedPool(ctx, ctx.user1, { aiw3Weight: 5000, aiAgentWeight: 5001 }),
+        "InvalidPoolWeights"
+      );
+    });
+
+    it("Should reject a weight above the maximum", async () => {
+      await TestSetup.expectError(
+        () => TestSetup.createWeightedPool(ctx, ctx.user1, { aiw3Weight: 9900, aiAgentWeight: 100 }),
+        "InvalidPoolWeights"
+      );
+    });
+  });
+});
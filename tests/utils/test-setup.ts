@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { Pump } from "../target/types/pump";
+import { Pump } from "../../target/types/pump";
 import {
   Connection,
   Keypair,
@@ -12,13 +12,14 @@ import {
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
-  getAssociatedTokenAddress,
-  createAssociatedTokenAccountInstruction,
-  createMintToInstruction,
-  createInitializeMintInstruction,
-  MINT_SIZE,
-  getMinimumBalanceForRentExemptMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  createMint,
+  createWrappedNativeAccount,
+  mintTo,
+  NATIVE_MINT,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import BN from "bn.js";
@@ -28,15 +29,85 @@ export interface TestContext {
   program: Program<Pump>;
   connection: Connection;
   payer: Keypair;
-  admin: Keypair;
+  admin: Keypair; // Config admin, the provider wallet so every test file shares it
   user1: Keypair;
   user2: Keypair;
   config: PublicKey;
-  tokenMintA: Keypair;
-  tokenMintB: Keypair;
+}
+
+export interface TestPool {
+  aiw3Mint: PublicKey;
+  aiAgentMint: PublicKey;
+  aiw3TokenProgram: PublicKey;
+  aiAgentTokenProgram: PublicKey;
   swapPool: PublicKey;
+  poolAiw3Vault: PublicKey;
+  poolAiAgentVault: PublicKey;
+  creator: Keypair;
+  creatorLiquidityPosition: PublicKey;
+}
+
+export interface TestToken {
+  mint: PublicKey;
+  swapPool: PublicKey;
+  poolTokenVault: PublicKey;
+  creatorTokenAccount: PublicKey;
+  vestingSchedule: PublicKey | null;
+  vestingVault: PublicKey | null;
+}
+
+export interface CreateTokenParams {
+  transferFeeBasisPoints: number;
+  maxFee: BN;
+  name: string;
+  symbol: string;
+  uri: string;
+  decimals: number;
+  initialSupply: BN;
+  poolAmount: BN;
+  vestingAmount: BN;
+  revokeMintAuthority: boolean;
 }
 
+export interface CreatePoolOptions {
+  aiw3Amount?: BN;
+  aiAgentAmount?: BN;
+  aiw3Weight?: number;
+  aiAgentWeight?: number;
+  swapFeeBasisPoints?: number;
+  launchProtection?: LaunchProtection;
+  aiAgentToken?: TestToken; // Token-2022 token of the creator, a fresh SPL Token mint by default
+  nativeSol?: boolean; // Quote the pool in wrapped SOL, wrapped from the creator's lamports
+}
+
+export interface LaunchProtection {
+  tradingStartTimestamp: BN | null;
+  launchProtectionSlots: BN;
+  maxBuyPerTx: BN;
+  maxBuyPerWallet: BN;
+  launchFeeBasisPoints: number;
+}
+
+export const NO_LAUNCH_PROTECTION: LaunchProtection = {
+  tradingStartTimestamp: null,
+  launchProtectionSlots: new BN(0),
+  maxBuyPerTx: new BN(0),
+  maxBuyPerWallet: new BN(0),
+  launchFeeBasisPoints: 0,
+};
+
+// UpdateConfigParams leaving every setting unchanged
+export const NO_CONFIG_UPDATE = {
+  feeRecipient: null,
+  operator: null,
+  guardian: null,
+  allowCreatorPoolUpdates: null,
+  tradeFeeBasisPoints: null,
+  pumpFee: null,
+  configUpdateDelay: null,
+  maxPriceAge: null,
+};
+
 export class TestSetup {
   static async initialize(): Promise<TestContext> {
     // Configure the client to use the local cluster
@@ -45,38 +116,17 @@ export class TestSetup {
     const program = anchor.workspace.Pump as Program<Pump>;
     const connection = provider.connection;
 
-    // Generate test keypairs
-    const payer = provider.wallet.payer;
-    const admin = Keypair.generate();
+    // The config is a singleton, so all test files share the wallet as admin
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const admin = payer;
     const user1 = Keypair.generate();
     const user2 = Keypair.generate();
 
     // Fund test accounts
-    await TestSetup.fundAccount(connection, payer, admin.publicKey, 10);
     await TestSetup.fundAccount(connection, payer, user1.publicKey, 5);
     await TestSetup.fundAccount(connection, payer, user2.publicKey, 5);
 
-    // Generate token mints
-    const tokenMintA = Keypair.generate();
-    const tokenMintB = Keypair.generate();
-
-    // Derive config PDA
-    const [config] = PublicKey.findProgramAddressSync(
-      [Buffer.from("config")],
-      program.programId
-    );
-
-    // Derive swap pool PDA
-    const [swapPool] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("swap_pool"),
-        tokenMintA.publicKey.toBuffer(),
-        tokenMintB.publicKey.toBuffer(),
-      ],
-      program.programId
-    );
-
-    return {
+    const ctx = {
       provider,
       program,
       connection,
@@ -84,11 +134,97 @@ export class TestSetup {
       admin,
       user1,
       user2,
-      config,
-      tokenMintA,
-      tokenMintB,
-      swapPool,
+      config: TestSetup.configPda(program.programId),
     };
+
+    await TestSetup.ensureConfig(ctx);
+
+    return ctx;
+  }
+
+  // Initialize the config with the admin as fee recipient and operator and
+  // no timelock, unless an earlier test file already did
+  static async ensureConfig(ctx: TestContext): Promise<void> {
+    if (await ctx.connection.getAccountInfo(ctx.config)) {
+      return;
+    }
+
+    const mopMint = await createMint(
+      ctx.connection,
+      ctx.payer,
+      ctx.admin.publicKey,
+      null,
+      TOKEN_DECIMALS
+    );
+
+    await ctx.program.methods
+      .initialize({
+        tradeFeeBasisPoints: SWAP_FEE_BASIS_POINTS,
+        pumpFee: new BN(0),
+        configUpdateDelay: new BN(0),
+      })
+      .accounts({
+        admin: ctx.admin.publicKey,
+        config: ctx.config,
+        mopMint,
+        feeRecipient: ctx.admin.publicKey,
+        feeRecipientMopVault: getAssociatedTokenAddressSync(mopMint, ctx.admin.publicKey),
+        operator: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.admin])
+      .rpc();
+  }
+
+  static configPda(programId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from("config")], programId)[0];
+  }
+
+  static swapPoolPda(programId: PublicKey, aiw3Mint: PublicKey, aiAgentMint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("swap_pool"), aiw3Mint.toBuffer(), aiAgentMint.toBuffer()],
+      programId
+    )[0];
+  }
+
+  static rolePda(programId: PublicKey, member: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), member.toBuffer()],
+      programId
+    )[0];
+  }
+
+  static tokenMintPda(programId: PublicKey, creator: PublicKey, symbol: string): PublicKey {
+    return PublicKey.findProgramAddressSync([creator.toBuffer(), Buffer.from(symbol)], programId)[0];
+  }
+
+  static vestingSchedulePda(programId: PublicKey, mint: PublicKey, beneficiary: PublicKey): PublicKey {
+    // Schedule id 0 is the grant made at token creation
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("vesting"), mint.toBuffer(), beneficiary.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      programId
+    )[0];
+  }
+
+  static launchBuyRecordPda(programId: PublicKey, swapPool: PublicKey, user: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("launch_buy_record"), swapPool.toBuffer(), user.toBuffer()],
+      programId
+    )[0];
+  }
+
+  static liquidityPositionPda(programId: PublicKey, swapPool: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("liquidity_position"), swapPool.toBuffer(), owner.toBuffer()],
+      programId
+    )[0];
+  }
+
+  // Temporary wrapped SOL account of a native SOL swap
+  static nativeSolPda(programId: PublicKey, user: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from("native_sol"), user.toBuffer()], programId)[0];
   }
 
   static async fundAccount(
@@ -108,65 +244,339 @@ export class TestSetup {
     await sendAndConfirmTransaction(connection, tx, [payer]);
   }
 
-  static async createToken(
-    connection: Connection,
-    payer: Keypair,
-    mint: Keypair,
-    decimals: number = 9
-  ): Promise<void> {
-    const lamports = await getMinimumBalanceForRentExemptMint(connection);
-    
-    const tx = new Transaction().add(
-      SystemProgram.createAccount({
-        fromPubkey: payer.publicKey,
-        newAccountPubkey: mint.publicKey,
-        space: MINT_SIZE,
-        lamports,
-        programId: TOKEN_PROGRAM_ID,
-      }),
-      createInitializeMintInstruction(
-        mint.publicKey,
-        decimals,
-        payer.publicKey,
-        payer.publicKey
-      )
-    );
+  // SPL Token mint without a freeze authority, `amount` minted to `owner`
+  static async createFundedMint(
+    ctx: TestContext,
+    owner: PublicKey,
+    amount: BN
+  ): Promise<PublicKey> {
+    const mint = await createMint(ctx.connection, ctx.payer, ctx.payer.publicKey, null, TOKEN_DECIMALS);
+    const account = await getOrCreateAssociatedTokenAccount(ctx.connection, ctx.payer, mint, owner);
+    await mintTo(ctx.connection, ctx.payer, mint, account.address, ctx.payer, BigInt(amount.toString()));
+    return mint;
+  }
 
-    await sendAndConfirmTransaction(connection, tx, [payer, mint]);
+  // Mint more of a test mint to `owner`, the payer is the mint authority
+  static async mintTokens(
+    ctx: TestContext,
+    mint: PublicKey,
+    owner: PublicKey,
+    amount: BN
+  ): Promise<PublicKey> {
+    const account = await TestSetup.tokenAccount(ctx, mint, owner);
+    await mintTo(ctx.connection, ctx.payer, mint, account, ctx.payer, BigInt(amount.toString()));
+    return account;
   }
 
-  static async createAssociatedTokenAccount(
-    connection: Connection,
-    payer: Keypair,
+  static async tokenAccount(
+    ctx: TestContext,
     mint: PublicKey,
-    owner: PublicKey
+    owner: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
   ): Promise<PublicKey> {
-    const ata = await getAssociatedTokenAddress(mint, owner);
-    
-    const tx = new Transaction().add(
-      createAssociatedTokenAccountInstruction(
-        payer.publicKey,
-        ata,
-        owner,
-        mint
-      )
+    const account = await getOrCreateAssociatedTokenAccount(
+      ctx.connection,
+      ctx.payer,
+      mint,
+      owner,
+      true,
+      undefined,
+      undefined,
+      tokenProgram
     );
+    return account.address;
+  }
 
-    await sendAndConfirmTransaction(connection, tx, [payer]);
-    return ata;
+  // Token-2022 mint created through `create_token`, defaults overridable per test
+  static async createToken(
+    ctx: TestContext,
+    creator: Keypair,
+    overrides: Partial<CreateTokenParams> = {}
+  ): Promise<TestToken> {
+    const params: CreateTokenParams = {
+      transferFeeBasisPoints: 100,
+      maxFee: new BN(1_000_000),
+      name: "Test Token",
+      symbol: `T${Math.floor(Math.random() * 1_000_000)}`,
+      uri: "https://example.com/token.json",
+      decimals: TOKEN_DECIMALS,
+      initialSupply: INITIAL_SUPPLY,
+      poolAmount: new BN(0),
+      vestingAmount: new BN(0),
+      revokeMintAuthority: false,
+      ...overrides,
+    };
+
+    const mint = TestSetup.tokenMintPda(ctx.program.programId, creator.publicKey, params.symbol);
+    const swapPool = PublicKey.findProgramAddressSync(
+      [Buffer.from("swap_pool"), mint.toBuffer()],
+      ctx.program.programId
+    )[0];
+    const poolTokenVault = PublicKey.findProgramAddressSync(
+      [swapPool.toBuffer(), mint.toBuffer()],
+      ctx.program.programId
+    )[0];
+    const creatorTokenAccount = getAssociatedTokenAddressSync(
+      mint, creator.publicKey, false, TOKEN_2022_PROGRAM_ID
+    );
+    const vests = !params.vestingAmount.isZero();
+    const vestingSchedule = vests
+      ? TestSetup.vestingSchedulePda(ctx.program.programId, mint, creator.publicKey)
+      : null;
+    const vestingVault = vestingSchedule
+      ? PublicKey.findProgramAddressSync(
+          [Buffer.from("vesting_vault"), vestingSchedule.toBuffer()],
+          ctx.program.programId
+        )[0]
+      : null;
+
+    const config = await ctx.program.account.config.fetch(ctx.config);
+
+    await ctx.program.methods
+      .createToken(params)
+      .accounts({
+        creator: creator.publicKey,
+        config: ctx.config,
+        tokenMint: mint,
+        swapPool,
+        poolTokenVault,
+        creatorTokenAccount,
+        vestingSchedule,
+        vestingVault,
+        feeRecipient: getAssociatedTokenAddressSync(config.mopMint, config.feeRecipient),
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([creator])
+      .rpc();
+
+    return { mint, swapPool, poolTokenVault, creatorTokenAccount, vestingSchedule, vestingVault };
   }
 
-  static async mintTokens(
-    connection: Connection,
-    payer: Keypair,
-    mint: PublicKey,
-    destination: PublicKey,
-    amount: number
-  ): Promise<void> {
-    const tx = new Transaction().add(
-      createMintToInstruction(mint, destination, payer.publicKey, amount)
+  // Weighted-product pool over fresh SPL Token mints funded by `creator`
+  static async createWeightedPool(
+    ctx: TestContext,
+    creator: Keypair,
+    options: CreatePoolOptions = {}
+  ): Promise<TestPool> {
+    const aiw3Amount = options.aiw3Amount ?? INITIAL_LIQUIDITY;
+    const aiAgentAmount = options.aiAgentAmount ?? INITIAL_LIQUIDITY;
+
+    const aiw3Mint = options.nativeSol
+      ? NATIVE_MINT
+      : await TestSetup.createFundedMint(ctx, creator.publicKey, INITIAL_SUPPLY);
+    if (options.nativeSol) {
+      await createWrappedNativeAccount(ctx.connection, creator, creator.publicKey, aiw3Amount.toNumber());
+    }
+    const aiAgentMint = options.aiAgentToken
+      ? options.aiAgentToken.mint
+      : await TestSetup.createFundedMint(ctx, creator.publicKey, INITIAL_SUPPLY);
+    const aiAgentTokenProgram = options.aiAgentToken ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
+    const swapPool = TestSetup.swapPoolPda(ctx.program.programId, aiw3Mint, aiAgentMint);
+    const creatorLiquidityPosition = TestSetup.liquidityPositionPda(
+      ctx.program.programId, swapPool, creator.publicKey
     );
-    await sendAndConfirmTransaction(connection, tx, [payer]);
+    const poolAiw3Vault = Keypair.generate();
+    const poolAiAgentVault = Keypair.generate();
+
+    await ctx.program.methods
+      .createPool({
+        poolType: { internal: {} },
+        initialAiw3Amount: aiw3Amount,
+        initialAiAgentAmount: aiAgentAmount,
+        swapFeeBasisPoints: options.swapFeeBasisPoints ?? SWAP_FEE_BASIS_POINTS,
+        curveType: { weightedProduct: {} },
+        aiw3Weight: options.aiw3Weight ?? 5000,
+        aiAgentWeight: options.aiAgentWeight ?? 5000,
+        restrictTransferHooks: false,
+        launchProtection: options.launchProtection ?? NO_LAUNCH_PROTECTION,
+      })
+      .accounts({
+        creator: creator.publicKey,
+        config: ctx.config,
+        adminOverride: null,
+        aiw3TokenMint: aiw3Mint,
+        aiAgentTokenMint: aiAgentMint,
+        swapPool,
+        creatorLiquidityPosition,
+        creatorAiw3Account: getAssociatedTokenAddressSync(aiw3Mint, creator.publicKey),
+        creatorAiAgentAccount: getAssociatedTokenAddressSync(
+          aiAgentMint, creator.publicKey, false, aiAgentTokenProgram
+        ),
+        poolAiw3Vault: poolAiw3Vault.publicKey,
+        poolAiAgentVault: poolAiAgentVault.publicKey,
+        aiw3HookApproval: null,
+        aiAgentHookApproval: null,
+        aiw3TokenProgram: TOKEN_PROGRAM_ID,
+        aiAgentTokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([creator, poolAiw3Vault, poolAiAgentVault])
+      .rpc();
+
+    return {
+      aiw3Mint,
+      aiAgentMint,
+      aiw3TokenProgram: TOKEN_PROGRAM_ID,
+      aiAgentTokenProgram,
+      swapPool,
+      poolAiw3Vault: poolAiw3Vault.publicKey,
+      poolAiAgentVault: poolAiAgentVault.publicKey,
+      creator,
+      creatorLiquidityPosition,
+    };
+  }
+
+  // Swap on a weighted pool, paying from and to the user's own accounts
+  static async swap(
+    ctx: TestContext,
+    pool: TestPool,
+    user: Keypair,
+    params: {
+      amountIn: BN;
+      minimumAmountOut: BN;
+      inputIsAiw3: boolean;
+      deadline?: object | null;
+      recipient?: PublicKey | null;
+      nativeSol?: boolean;
+    },
+    accounts: { recipientOutputAccount?: PublicKey | null; launchBuyRecord?: PublicKey | null } = {}
+  ): Promise<string> {
+    return ctx.program.methods
+      .swap({
+        amountIn: params.amountIn,
+        minimumAmountOut: params.minimumAmountOut,
+        inputIsAiw3: params.inputIsAiw3,
+        nativeSol: params.nativeSol ?? false,
+        deadline: params.deadline ?? null,
+        recipient: params.recipient ?? null,
+      })
+      .accounts({
+        user: user.publicKey,
+        config: ctx.config,
+        aiw3TokenMint: pool.aiw3Mint,
+        aiAgentTokenMint: pool.aiAgentMint,
+        swapPool: pool.swapPool,
+        userAiw3Account: getAssociatedTokenAddressSync(
+          pool.aiw3Mint, user.publicKey, false, pool.aiw3TokenProgram
+        ),
+        userAiAgentAccount: getAssociatedTokenAddressSync(
+          pool.aiAgentMint, user.publicKey, false, pool.aiAgentTokenProgram
+        ),
+        recipientOutputAccount: accounts.recipientOutputAccount ?? null,
+        nativeSolAccount: params.nativeSol
+          ? TestSetup.nativeSolPda(ctx.program.programId, user.publicKey)
+          : null,
+        poolAiw3Vault: pool.poolAiw3Vault,
+        poolAiAgentVault: pool.poolAiAgentVault,
+        aw3PriceOracle: null,
+        aiw3HookApproval: null,
+        aiAgentHookApproval: null,
+        launchBuyRecord: accounts.launchBuyRecord ?? null,
+        aiw3TokenProgram: pool.aiw3TokenProgram,
+        aiAgentTokenProgram: pool.aiAgentTokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  // Buy `shares` of a weighted pool with the user's own tokens
+  static async addLiquidity(
+    ctx: TestContext,
+    pool: TestPool,
+    user: Keypair,
+    params: { shares: BN; maxAiw3Amount: BN; maxAiAgentAmount: BN }
+  ): Promise<string> {
+    return ctx.program.methods
+      .addLiquidity(params)
+      .accounts({
+        user: user.publicKey,
+        config: ctx.config,
+        aiw3TokenMint: pool.aiw3Mint,
+        aiAgentTokenMint: pool.aiAgentMint,
+        swapPool: pool.swapPool,
+        liquidityPosition: TestSetup.liquidityPositionPda(ctx.program.programId, pool.swapPool, user.publicKey),
+        userAiw3Account: getAssociatedTokenAddressSync(pool.aiw3Mint, user.publicKey, false, pool.aiw3TokenProgram),
+        userAiAgentAccount: getAssociatedTokenAddressSync(
+          pool.aiAgentMint, user.publicKey, false, pool.aiAgentTokenProgram
+        ),
+        poolAiw3Vault: pool.poolAiw3Vault,
+        poolAiAgentVault: pool.poolAiAgentVault,
+        aiw3HookApproval: null,
+        aiAgentHookApproval: null,
+        aiw3TokenProgram: pool.aiw3TokenProgram,
+        aiAgentTokenProgram: pool.aiAgentTokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  // Redeem `shares` of a weighted pool into the user's own accounts
+  static async removeLiquidity(
+    ctx: TestContext,
+    pool: TestPool,
+    user: Keypair,
+    params: { shares: BN; minAiw3Amount: BN; minAiAgentAmount: BN }
+  ): Promise<string> {
+    return ctx.program.methods
+      .removeLiquidity(params)
+      .accounts({
+        user: user.publicKey,
+        config: ctx.config,
+        aiw3TokenMint: pool.aiw3Mint,
+        aiAgentTokenMint: pool.aiAgentMint,
+        swapPool: pool.swapPool,
+        liquidityPosition: TestSetup.liquidityPositionPda(ctx.program.programId, pool.swapPool, user.publicKey),
+        userAiw3Account: getAssociatedTokenAddressSync(pool.aiw3Mint, user.publicKey, false, pool.aiw3TokenProgram),
+        userAiAgentAccount: getAssociatedTokenAddressSync(
+          pool.aiAgentMint, user.publicKey, false, pool.aiAgentTokenProgram
+        ),
+        poolAiw3Vault: pool.poolAiw3Vault,
+        poolAiAgentVault: pool.poolAiAgentVault,
+        aiw3HookApproval: null,
+        aiAgentHookApproval: null,
+        aiw3TokenProgram: pool.aiw3TokenProgram,
+        aiAgentTokenProgram: pool.aiAgentTokenProgram,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  // Current output of a swap, read with the `quote` view
+  static async quote(
+    ctx: TestContext,
+    pool: TestPool,
+    amountIn: BN,
+    inputIsAiw3: boolean
+  ): Promise<BN> {
+    return ctx.program.methods
+      .quote({ amountIn, inputIsAiw3 })
+      .accounts({
+        config: ctx.config,
+        swapPool: pool.swapPool,
+        aw3PriceOracle: null,
+      })
+      .view();
+  }
+
+  // Pause or resume a pool as the admin
+  static async setPoolStatus(ctx: TestContext, pool: TestPool, isActive: boolean): Promise<void> {
+    await ctx.program.methods
+      .setPoolStatus({ isActive, reasonCode: 0 })
+      .accounts({
+        authority: ctx.admin.publicKey,
+        roleAccount: null,
+        config: ctx.config,
+        swapPool: pool.swapPool,
+      })
+      .signers([ctx.admin])
+      .rpc();
   }
 
   static async sleep(ms: number): Promise<void> {
@@ -184,7 +594,7 @@ export class TestSetup {
     message?: string
   ): void {
     const diff = actual.sub(expected).abs();
-    expect(diff.lte(tolerance)).to.be.true;
+    expect(diff.lte(tolerance), message).to.be.true;
   }
 
   static async getTokenBalance(
@@ -195,16 +605,22 @@ export class TestSetup {
     return new BN(accountInfo.value.amount);
   }
 
-  static async getAccountInfo(
-    program: Program<Pump>,
-    account: PublicKey
-  ): Promise<any> {
-    return await program.account.fetch(account);
+  // Run `fn` and check it fails with `errorName`
+  static async expectError(fn: () => Promise<unknown>, errorName: string): Promise<void> {
+    try {
+      await fn();
+    } catch (error) {
+      expect(String(error)).to.include(errorName);
+      return;
+    }
+    expect.fail(`Expected ${errorName}`);
   }
 }
 
+export { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID };
+
 export const SWAP_FEE_BASIS_POINTS = 30; // 0.3%
 export const MAX_FEE_BASIS_POINTS = 10000;
-export const TOKEN_DECIMALS = 9;
+export const TOKEN_DECIMALS = 6;
 export const INITIAL_SUPPLY = new BN(1_000_000).mul(new BN(10).pow(new BN(TOKEN_DECIMALS)));
-export const INITIAL_LIQUIDITY = new BN(100_000).mul(new BN(10).pow(new BN(TOKEN_DECIMALS))); 
\ No newline at end of file
+export const INITIAL_LIQUIDITY = new BN(100_000).mul(new BN(10).pow(new BN(TOKEN_DECIMALS)));